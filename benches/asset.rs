@@ -0,0 +1,14 @@
+use antelope::Asset;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn display(c: &mut Criterion) {
+    let mut group = c.benchmark_group("asset_to_string");
+    for s in ["100 SYS", "1.0000 EOS", "-0.00000001 BTC", "12345678.123456789012 SYM"] {
+        let asset = Asset::from(s);
+        group.bench_function(s, |b| b.iter(|| black_box(asset).to_string()));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, display);
+criterion_main!(benches);
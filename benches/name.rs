@@ -0,0 +1,25 @@
+use std::str::FromStr;
+
+use antelope::Name;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn decode(c: &mut Criterion) {
+    let names = ["eosio", "eosio.token", "myaccount123", "zzzzzzzzzzzzj"].map(Name::from);
+    c.bench_function("name_to_string", |b| {
+        b.iter(|| {
+            for name in &names {
+                black_box(black_box(name).to_string());
+            }
+        })
+    });
+    c.bench_function("name_from_str", |b| {
+        b.iter(|| {
+            for s in ["eosio", "eosio.token", "myaccount123", "zzzzzzzzzzzzj"] {
+                black_box(Name::from_str(black_box(s)).unwrap());
+            }
+        })
+    });
+}
+
+criterion_group!(benches, decode);
+criterion_main!(benches);
@@ -0,0 +1,132 @@
+use crate::{Name, Pack, ParseError, PermissionLevel, Unpack};
+
+/// The `Action` struct represents a contract action and its authorizations
+///
+/// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/contracts/eosio/action.hpp>
+///
+/// `data` holds the packed action payload, e.g. the packed `transfer` struct
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Action, Name, PermissionLevel};
+///
+/// let auth = PermissionLevel::new(Name::from("alice"), Name::from("active"));
+/// let mut action = Action::new(Name::from("eosio.token"), Name::from("transfer"), vec![auth]);
+/// action.set_data(&"memo".to_string());
+/// assert_eq!(vec![4, b'm', b'e', b'm', b'o'], action.data);
+/// ```
+#[derive(Eq, Clone, Debug, PartialEq, Default)]
+pub struct Action {
+    pub account: Name,
+    pub name: Name,
+    pub authorization: Vec<PermissionLevel>,
+    pub data: Vec<u8>,
+}
+
+impl Action {
+    /// Returns a new action with empty data
+    #[inline]
+    #[must_use]
+    pub fn new(account: Name, name: Name, authorization: Vec<PermissionLevel>) -> Self {
+        Action {
+            account,
+            name,
+            authorization,
+            data: Vec::new(),
+        }
+    }
+
+    /// Replaces the data with the packed `payload`
+    pub fn set_data<T: Pack>(&mut self, payload: &T) {
+        self.data = payload.to_packed();
+    }
+}
+
+impl Pack for Action {
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.account.pack(out);
+        self.name.pack(out);
+        self.authorization.pack(out);
+        self.data.pack(out);
+    }
+}
+
+impl Unpack for Action {
+    fn unpack(data: &[u8], pos: &mut usize) -> Result<Self, ParseError> {
+        let account = Name::unpack(data, pos)?;
+        let name = Name::unpack(data, pos)?;
+        let authorization = Vec::<PermissionLevel>::unpack(data, pos)?;
+        let data = Vec::<u8>::unpack(data, pos)?;
+        Ok(Action {
+            account,
+            name,
+            authorization,
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Asset;
+
+    struct Transfer {
+        from: Name,
+        to: Name,
+        quantity: Asset,
+        memo: String,
+    }
+
+    impl Pack for Transfer {
+        fn pack(&self, out: &mut Vec<u8>) {
+            self.from.pack(out);
+            self.to.pack(out);
+            self.quantity.pack(out);
+            self.memo.pack(out);
+        }
+    }
+
+    fn transfer_action() -> Action {
+        let mut action = Action::new(
+            Name::from("eosio.token"),
+            Name::from("transfer"),
+            vec![PermissionLevel::new(Name::from("alice"), Name::from("active"))],
+        );
+        action.set_data(&Transfer {
+            from: Name::from("alice"),
+            to: Name::from("bob"),
+            quantity: Asset::from("1.0000 EOS"),
+            memo: "hi".to_string(),
+        });
+        action
+    }
+
+    #[test]
+    fn test_pack_transfer() {
+        let packed = transfer_action().to_packed();
+        let name = |s| Name::from(s).value.to_le_bytes();
+
+        assert_eq!(packed[0..8], name("eosio.token"));
+        assert_eq!(packed[8..16], name("transfer"));
+        assert_eq!(packed[16], 1); // one authorization
+        assert_eq!(packed[17..25], name("alice"));
+        assert_eq!(packed[25..33], name("active"));
+        assert_eq!(packed[33], 35); // data length
+        assert_eq!(packed[34..42], name("alice"));
+        assert_eq!(packed[42..50], name("bob"));
+        assert_eq!(packed[50..58], 10000_i64.to_le_bytes());
+        assert_eq!(packed[58..66], [4, b'E', b'O', b'S', 0, 0, 0, 0]);
+        assert_eq!(packed[66..], [2, b'h', b'i']);
+    }
+
+    #[test]
+    fn test_unpack() {
+        let action = transfer_action();
+        let packed = action.to_packed();
+        assert_eq!(Action::from_packed(&packed), Ok(action));
+        assert_eq!(Action::from_packed(&packed[..packed.len() - 1]), Err(ParseError::UnexpectedEof));
+        assert_eq!(Action::from_packed(&[0; 18]), Ok(Action::default()));
+    }
+}
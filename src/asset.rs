@@ -1,6 +1,9 @@
 use std::str::FromStr;
 
-use crate::{check, ParseError, Symbol, SymbolCode};
+use crate::{check, try_check, CurrencyFormat, Pack, ParseError, Ratio, Rounding, Symbol, SymbolCode, Unpack};
+
+#[cfg(feature = "crypto")]
+use crate::Checksum256;
 // use std::convert::From;
 /// The `Asset` struct represents a asset
 ///
@@ -14,15 +17,27 @@ use crate::{check, ParseError, Symbol, SymbolCode};
 /// let quantity = Asset::from_amount(10000, Symbol::from("4,FOO"));
 /// assert_eq!(10000, quantity.amount);
 /// ```
-#[derive(Eq, Copy, Clone, Debug, Default)]
+#[derive(Eq, Copy, Clone, Default)]
 pub struct Asset {
     pub amount: i64,
     pub symbol: Symbol,
 }
 
 impl Asset {
+    /// Maximum amount of an asset, `2^62 - 1`
     pub const MAX_AMOUNT: i64 = (1 << 62) - 1;
 
+    /// Minimum amount of an asset, `-(2^62 - 1)`
+    ///
+    /// The valid range is symmetric around zero, so `MIN_AMOUNT == -MAX_AMOUNT` rather than `i64::MIN`
+    pub const MIN_AMOUNT: i64 = -Self::MAX_AMOUNT;
+
+    /**
+     * Default constructor
+     *
+     * The symbol is intentionally empty, as in the CDT; such an asset isn't valid, and its code is
+     * reported by `SymbolCode::is_system_reserved`
+     */
     #[inline]
     #[must_use]
     pub fn new() -> Self {
@@ -39,822 +54,3813 @@ impl Asset {
     }
 
     /**
-     * Check if the amount doesn't exceed the max amount
+     * Construct an asset from an integer amount in the smallest unit, e.g. a ledger column
      *
-     * @return true - if the amount doesn't exceed the max amount
-     * @return false - otherwise
+     * @param units - The amount in the smallest unit, e.g. 10000 for "1.0000 EOS"
+     * @param symbol - The symbol of the asset
+     * @return asset - panics if `units` is out of the valid range
      */
-    pub fn is_amount_within_range(&self) -> bool {
-        -Asset::MAX_AMOUNT <= self.amount && self.amount <= Asset::MAX_AMOUNT
+    #[must_use]
+    pub fn from_minimal_units(units: i64, symbol: Symbol) -> Self {
+        let asset = Asset::from_amount(units, symbol);
+        check(asset.is_amount_within_range(), "magnitude of asset amount must be less than 2^62");
+        asset
     }
 
     /**
-     * Check if the asset is valid. %A valid asset has its amount <= max_amount and its symbol name valid
-     *
-     * @return true - if the asset is valid
-     * @return false - otherwise
+     * The amount in the smallest unit, e.g. 10000 for "1.0000 EOS"
      */
-    pub fn is_valid(&self) -> bool {
-        self.is_amount_within_range() && self.symbol.is_valid()
+    #[inline]
+    #[must_use]
+    pub fn to_minimal_units(&self) -> i64 {
+        self.amount
     }
 
     /**
-     * Set the amount of the asset
+     * Sum raw amounts, e.g. read from a column, into an asset
      *
-     * @param a - New amount for the asset
+     * The amounts are accumulated in `i128`, so only the total has to be within range
+     *
+     * @param amounts - The amounts to sum
+     * @param symbol - The symbol of the resulting asset
+     * @return asset - or `OutOfRange` if the total exceeds the valid range
      */
-    pub fn set_amount(mut self, amount: i64) {
-        self.amount = amount;
-        check(self.is_amount_within_range(), "magnitude of asset amount must be less than 2^62")
+    pub fn from_amounts(amounts: impl IntoIterator<Item = i64>, symbol: Symbol) -> Result<Asset, ParseError> {
+        let total: i128 = amounts.into_iter().map(|amount| amount as i128).sum();
+        if !(Asset::MIN_AMOUNT as i128..=Asset::MAX_AMOUNT as i128).contains(&total) {
+            return Err(ParseError::OutOfRange);
+        }
+        Ok(Asset::from_amount(total as i64, symbol))
     }
 
     /**
-     * @return float value of amount
+     * Construct an asset without any validation, for data that is already known to be valid
+     *
+     * Like `from_amount`, nothing is checked; the `unsafe` marker makes trusted call sites,
+     * e.g. decoders of bytes that came straight off the wire, explicit and greppable.
+     *
+     * # Safety
+     *
+     * The caller must guarantee that `amount` is within `[MIN_AMOUNT, MAX_AMOUNT]` and that
+     * `symbol` is valid. Breaking this doesn't cause undefined behavior, but the arithmetic
+     * operators may panic and `Display` may produce output that doesn't parse back.
+     *
+     * @param amount - The amount of the asset
+     * @param symbol - The symbol of the asset
      */
-    pub fn value(&self) -> f64 {
-        self.amount as f64 / 10_f64.powi(self.symbol.precision() as i32)
+    #[inline]
+    #[must_use]
+    pub const unsafe fn from_amount_unchecked(amount: i64, symbol: Symbol) -> Self {
+        Asset { amount, symbol }
     }
-}
 
-impl std::fmt::Display for Asset {
     /**
-     * Converts the asset into string
+     * Safe alias of `from_amount_unchecked`, skipping the range and symbol checks
      *
-     * @return String in the form of "1.2345 SYM" format, where SYM symbol has precision equal to 4
+     * The caller is expected to uphold the same invariants as for `from_amount_unchecked`
+     *
+     * @param amount - The amount of the asset
+     * @param symbol - The symbol of the asset
      */
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let whole = self.amount / 10_i64.pow(self.symbol.precision().min(18) as u32);
-
-        let decimal: String = (0..self.symbol.precision() as usize)
-            .rev()
-            .map(|i| (self.amount.abs() / 10_i64.pow(i.min(18) as u32)) % 10)
-            .map(|digit| (b'0' + (digit as u8)) as char)
-            .collect();
-
-        if decimal.is_empty() {
-            write!(f, "{} {}", whole, self.symbol.code())
-        } else {
-            write!(f, "{}.{} {}", whole, decimal, self.symbol.code())
-        }
+    #[inline]
+    #[must_use]
+    pub const fn from_amount_trusted(amount: i64, symbol: Symbol) -> Self {
+        Asset { amount, symbol }
     }
-}
 
-impl From<&str> for Asset {
     /**
-     * Parse Asset from string formatted as "1.2345 SYM@contract"
+     * Check if the amount doesn't exceed the max amount
      *
+     * @return true - if the amount doesn't exceed the max amount
+     * @return false - otherwise
      */
-    fn from(s: &str) -> Self {
-        Self::from_str(s).unwrap_or_else(|e| panic!("failed to parse asset from string: {}", e))
+    pub fn is_amount_within_range(&self) -> bool {
+        Asset::MIN_AMOUNT <= self.amount && self.amount <= Asset::MAX_AMOUNT
     }
-}
-
-impl FromStr for Asset {
-    type Err = ParseError;
 
+    /**
+     * Construct a zero asset of the given symbol
+     *
+     * @param symbol - The symbol of the asset
+     * @return asset - with an amount of zero
+     */
     #[inline]
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split(' ').collect();
-        if parts.len() != 2 {
-            return Err(ParseError::BadFormat);
-        }
-        let (amount_str, symbol_str) = (parts[0], parts[1]);
-        let precision = match amount_str.find('.') {
-            Some(idx) => (amount_str.len() - idx - 1) as u8,
-            None => 0,
-        };
-        let amount = match amount_str.replace('.', "").parse::<i64>() {
-            Ok(amount) => amount,
-            Err(_) => return Err(ParseError::BadAmount(amount_str.to_string())),
-        };
-        let sym_code = symbol_str
-            .parse::<SymbolCode>()
-            .map_err(|_| ParseError::BadSymbolCode(symbol_str.to_string()))?;
-        let symbol = Symbol::from_precision(sym_code, precision);
-
-        Ok(Asset { amount, symbol })
+    #[must_use]
+    pub const fn zero(symbol: Symbol) -> Self {
+        Asset { amount: 0, symbol }
     }
-}
 
-impl AsRef<Asset> for Asset {
+    /**
+     * Zero `4,EOS` asset, built at compile time
+     */
     #[inline]
     #[must_use]
-    fn as_ref(&self) -> &Asset {
-        self
+    pub const fn zero_eos() -> Self {
+        Asset::zero(Symbol::eos())
     }
-}
 
-impl std::ops::Neg for Asset {
-    type Output = Asset;
     /**
-     * Negate the amount of the asset
-     *
-     * @return a new asset with the negated amount
+     * Zero `8,WAX` asset, built at compile time
      */
-    fn neg(self) -> Asset {
-        Asset {
-            amount: -self.amount,
-            symbol: self.symbol,
-        }
+    #[inline]
+    #[must_use]
+    pub const fn zero_wax() -> Self {
+        Asset::zero(Symbol::wax())
     }
-}
 
-impl std::cmp::PartialEq for Asset {
-    fn eq(&self, other: &Asset) -> bool {
-        check(
-            self.symbol == other.symbol,
-            "comparison of assets with different symbols is not allowed",
-        );
-        self.amount == other.amount
+    /**
+     * Zero `4,TLOS` asset, built at compile time
+     */
+    #[inline]
+    #[must_use]
+    pub const fn zero_tlos() -> Self {
+        Asset::zero(Symbol::tlos())
     }
-}
 
-impl std::cmp::PartialOrd for Asset {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+    /**
+     * Zero `4,USDT` asset, built at compile time
+     */
+    #[inline]
+    #[must_use]
+    pub const fn zero_usdt() -> Self {
+        Asset::zero(Symbol::usdt())
     }
-}
-
-impl std::cmp::Ord for Asset {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        check(
-            self.symbol == other.symbol,
-            "comparison of assets with different symbols is not allowed",
-        );
 
-        self.amount.cmp(&other.amount)
+    /**
+     * Zero `4,SYS` asset, built at compile time
+     */
+    #[inline]
+    #[must_use]
+    pub const fn zero_sys() -> Self {
+        Asset::zero(Symbol::sys())
     }
-}
 
-impl std::ops::SubAssign for Asset {
     /**
-     * Subtraction assignment operator
-     *
-     * @param other - Another asset to subtract this asset with
-     * @post The amount of this asset is subtracted by the amount of asset `other`
+     * Check if the amount is zero, whatever the symbol
      */
-    fn sub_assign(&mut self, other: Asset) {
-        assert_eq!(self.symbol, other.symbol, "attempt to subtract asset with different symbol");
-        self.amount -= other.amount;
-        check(-Asset::MAX_AMOUNT <= self.amount, "subtraction underflow");
-        check(self.amount <= Asset::MAX_AMOUNT, "subtraction overflow");
+    #[inline]
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.amount == 0
     }
-}
 
-impl std::ops::AddAssign for Asset {
     /**
-     * Addition Assignment  operator
+     * Check if the asset is the one of `Asset::new()`, with an empty symbol and a zero amount,
+     * e.g. a field that was never set
      *
-     * @param a - Another asset to add with this asset
-     * @post The amount of this asset is added with the amount of asset a
+     * Comparing it with `==` to a real asset panics on the symbol mismatch, whereas `nominal_eq`
+     * returns false and `cmp_checked` an error
      */
-    fn add_assign(&mut self, a: Self) {
-        assert_eq!(self.symbol, a.symbol, "attempt to add asset with different symbol");
-        self.amount += a.amount;
-        assert!(-Self::MAX_AMOUNT <= self.amount, "addition underflow");
-        assert!(self.amount <= Self::MAX_AMOUNT, "addition overflow");
+    #[inline]
+    #[must_use]
+    pub fn is_uninitialized(&self) -> bool {
+        self.symbol.raw() == 0 && self.amount == 0
     }
-}
 
-impl std::ops::MulAssign<i64> for Asset {
     /**
-     * Multiplication assignment operator, with a number
+     * Check if the asset is valid. %A valid asset has its amount <= max_amount and its symbol name valid
      *
-     * @details Multiplication assignment operator. Multiply the amount of this asset with a number and then assign the value to itself.
-     * @param a - The multiplier for the asset's amount
-     * @return asset - Reference to this asset
-     * @post The amount of this asset is multiplied by a
+     * @return true - if the asset is valid
+     * @return false - otherwise
      */
-    fn mul_assign(&mut self, a: i64) {
-        let tmp = (self.amount as i128) * (a as i128);
-        assert!(tmp <= Self::MAX_AMOUNT as i128, "multiplication overflow");
-        assert!(tmp >= -(Self::MAX_AMOUNT as i128), "multiplication underflow");
-        self.amount = tmp as i64;
+    pub fn is_valid(&self) -> bool {
+        self.is_amount_within_range() && self.symbol.is_valid()
     }
-}
 
-impl std::ops::DivAssign<i64> for Asset {
     /**
-     * Division assignment operator, with a number proceeding
+     * Set the amount of the asset
      *
-     * @brief Division assignment operator, with a number proceeding
-     * @param self - The asset to be divided
-     * @param a - The divisor for the asset's amount
-     * @return asset - Reference to the asset, which has been divided
+     * @param a - New amount for the asset
      */
-    fn div_assign(&mut self, a: i64) {
-        check(a != 0, "divide by zero");
-        check(!(self.amount == std::i64::MIN && a == -1), "signed division overflow");
-        self.amount /= a;
+    pub fn set_amount(mut self, amount: i64) {
+        self.amount = amount;
+        check(self.is_amount_within_range(), "magnitude of asset amount must be less than 2^62")
     }
-}
 
-impl std::ops::Add for Asset {
-    type Output = Self;
+    /**
+     * @return float value of amount
+     */
+    pub fn value(&self) -> f64 {
+        self.amount as f64 / 10_f64.powi(self.symbol.precision() as i32)
+    }
 
     /**
-     * Addition operator
+     * Float values of a slice of assets, as `value()` of each
      *
-     * @param other - The second asset to be added to the first asset
-     * @return asset - New asset as the result of addition
+     * Like `value()`, amounts beyond 2^53 lose precision
+     *
+     * @param assets - The assets
+     * @return values - in the order of `assets`
      */
-    fn add(self, other: Self) -> Self {
-        let mut result = self;
-        result += other;
-        result
+    #[must_use]
+    pub fn values(assets: &[Asset]) -> Vec<f64> {
+        assets.iter().map(Asset::value).collect()
     }
-}
-
-impl std::ops::Sub for Asset {
-    type Output = Self;
 
     /**
-     * Subtraction operator
+     * Total float value of assets of a single symbol
      *
-     * @param other - The asset used to subtract from the first asset
-     * @return asset - New asset as the result of subtraction
+     * The amounts are summed exactly in 128 bits and converted to `f64` once, so rounding
+     * errors do not add up as summing `value()`s would
+     *
+     * @param assets - The assets, all of the same symbol
+     * @return total - `Some(0.0)` for an empty slice, or `None` as soon as a symbol differs
      */
-    fn sub(self, other: Self) -> Self {
-        let mut result = self;
-        result -= other;
-        result
+    #[must_use]
+    pub fn total_value_same_symbol(assets: &[Asset]) -> Option<f64> {
+        let Some(first) = assets.first() else {
+            return Some(0.0);
+        };
+        let mut total: i128 = 0;
+        for asset in assets {
+            if asset.symbol != first.symbol {
+                return None;
+            }
+            total += asset.amount as i128;
+        }
+        Some(total as f64 / 10_f64.powi(first.symbol.precision() as i32))
     }
-}
-
-impl std::ops::Mul<i64> for Asset {
-    type Output = Asset;
 
     /**
-     * Multiplication operator, with a number proceeding
+     * SHA-256 digest of the packed asset, e.g. for a Merkle tree of balances
      *
-     * @brief Multiplication operator, with a number proceeding
-     * @param a - The asset to be multiplied
-     * @param b - The multiplier for the asset's amount
-     * @return asset - New asset as the result of multiplication
+     * Equal assets always have the same digest, as the packed form is canonical
+     *
+     * @return checksum - The digest of the 16 packed bytes
      */
-    fn mul(self, b: i64) -> Asset {
-        let mut result = self;
-        result *= b;
-        result
+    #[cfg(feature = "crypto")]
+    #[must_use]
+    pub fn digest(&self) -> Checksum256 {
+        Checksum256::hash(&self.to_packed())
     }
-}
-
-impl std::ops::Mul<Asset> for i64 {
-    type Output = Asset;
 
     /**
-     * Multiplication operator, with a number preceeding
+     * Split the asset into normalized columns, e.g. for a CSV or TSV export
      *
-     * @param a - The multiplier for the asset's amount
-     * @param b - The asset to be multiplied
-     * @return asset - New asset as the result of multiplication
+     * @return fields - `(amount, precision, code)`, e.g. `(12345, 2, "SYM")` for "123.45 SYM"
      */
-    fn mul(self, a: Asset) -> Asset {
-        a * self
+    #[must_use]
+    pub fn to_csv_fields(&self) -> (i64, u8, String) {
+        (self.amount, self.symbol.precision(), self.symbol.code().to_string())
     }
-}
-
-impl std::ops::Div<i64> for Asset {
-    type Output = Asset;
 
     /**
-     * Division operator, with a number proceeding
+     * Scale the amount to 18 decimals, as the wei of EVM tokens, e.g. 1234500000000000000 for "1.2345 SYM"
      *
-     * @param a - The asset to be divided
-     * @param b - The divisor for the asset's amount
-     * @return asset - New asset as the result of division
+     * A precision above 18 is truncated toward zero
+     *
+     * @return value - The amount in units of 10^-18
      */
-    fn div(self, b: i64) -> Asset {
-        let mut result = self;
-        result /= b;
+    #[must_use]
+    pub fn to_e18(&self) -> i128 {
+        let precision = self.symbol.precision() as u32;
+        if precision <= 18 {
+            self.amount as i128 * 10_i128.pow(18 - precision)
+        } else {
+            10_i128.checked_pow(precision - 18).map_or(0, |factor| self.amount as i128 / factor)
+        }
+    }
+
+    /**
+     * Construct an asset from an amount in 18 decimals, rounded half-up to the precision of `symbol`
+     *
+     * @param value - The amount in units of 10^-18
+     * @param symbol - The symbol of the asset
+     * @return asset - or `OutOfRange` if the amount exceeds the valid range
+     */
+    pub fn from_e18(value: i128, symbol: Symbol) -> Result<Asset, ParseError> {
+        let precision = symbol.precision() as u32;
+        let amount = if precision <= 18 {
+            Rounding::HalfUp.div(value, 10_i128.pow(18 - precision))
+        } else {
+            match 10_i128.checked_pow(precision - 18) {
+                Some(factor) => value.checked_mul(factor),
+                None => (value == 0).then_some(0),
+            }
+            .ok_or(ParseError::OutOfRange)?
+        };
+        try_check(amount.abs() <= Asset::MAX_AMOUNT as i128, ParseError::OutOfRange)?;
+        Ok(Asset::from_amount(amount as i64, symbol))
+    }
+
+    /**
+     * Converts the asset into a JSON value for action arguments, the string form nodeos expects
+     *
+     * @return value - A JSON string, e.g. `"1.0000 EOS"`
+     */
+    #[cfg(feature = "serde_json")]
+    #[must_use]
+    pub fn to_json_value(&self) -> serde_json::Value {
+        serde_json::Value::String(self.to_string())
+    }
+
+    /**
+     * Split the asset into an amount column and a symbol column, the common storage pattern
+     *
+     * @return parts - `(amount, symbol)`, e.g. `(10000, "4,EOS")` for "1.0000 EOS"
+     */
+    #[must_use]
+    pub fn into_db_parts(self) -> (i64, String) {
+        (self.amount, self.symbol.to_string())
+    }
+
+    /**
+     * Construct an asset from the columns of `into_db_parts`
+     *
+     * @param amount - The amount in the smallest unit
+     * @param symbol - The symbol, e.g. "4,EOS"
+     * @return asset - or the error of parsing `symbol`, `OutOfRange` if the amount exceeds the valid range
+     */
+    pub fn from_db_parts(amount: i64, symbol: &str) -> Result<Asset, ParseError> {
+        let asset = Asset::from_amount(amount, symbol.parse()?);
+        try_check(asset.is_amount_within_range(), ParseError::OutOfRange)?;
+        Ok(asset)
+    }
+
+    /**
+     * Addition of assets of the same code, rescaling the one of lower precision up to the other
+     *
+     * Unlike `+`, "1.0 SYM" and "1.00 SYM" can be added, giving "2.00 SYM"
+     *
+     * @param other - The asset to add
+     * @return asset - at the higher precision, or `BadSymbol` if the codes differ, `OutOfRange` on overflow
+     */
+    pub fn add_rescaled(self, other: Asset) -> Result<Asset, ParseError> {
+        if !self.symbol.same_code(&other.symbol) {
+            return Err(ParseError::BadSymbol(other.symbol.to_string()));
+        }
+        let symbol = if self.symbol.precision() >= other.symbol.precision() {
+            self.symbol
+        } else {
+            other.symbol
+        };
+        let lhs = shift_amount(self.amount, self.symbol.precision(), symbol.precision())?;
+        let rhs = shift_amount(other.amount, other.symbol.precision(), symbol.precision())?;
+        Asset::from_amount(lhs, symbol)
+            .checked_add(Asset::from_amount(rhs, symbol))
+            .ok_or(ParseError::OutOfRange)
+    }
+
+    /**
+     * Check if two assets have the same code and the same value, whatever their precision,
+     * e.g. "1.0000 EOS" and "1.000 EOS"
+     *
+     * Unlike `==`, this never panics on different symbols, so an uninitialized asset is only
+     * equal to another uninitialized one
+     *
+     * @param other - The asset to compare with
+     * @return true - if the codes and the values are equal
+     */
+    #[must_use]
+    pub fn nominal_eq(&self, other: &Asset) -> bool {
+        if !self.symbol.same_code(&other.symbol) {
+            return false;
+        }
+        let precision = self.symbol.precision().max(other.symbol.precision());
+        let lhs = shift_amount(self.amount, self.symbol.precision(), precision);
+        let rhs = shift_amount(other.amount, other.symbol.precision(), precision);
+        matches!((lhs, rhs), (Ok(lhs), Ok(rhs)) if lhs == rhs)
+    }
+
+    /**
+     * Check if the values of two assets are within `epsilon` of each other, whatever their symbols,
+     * e.g. a computed asset against an oracle value of another precision
+     *
+     * The values are `f64`, which represents amounts exactly only up to 2^53, so use a relative
+     * `epsilon` for large values, and `nominal_eq` for exact comparisons of the same code
+     *
+     * @param other - The asset to compare with
+     * @param epsilon - The largest difference of the values considered equal
+     * @return true - if `|self.value() - other.value()| <= epsilon`
+     */
+    #[must_use]
+    pub fn value_approx_eq(&self, other: &Asset, epsilon: f64) -> bool {
+        (self.value() - other.value()).abs() <= epsilon
+    }
+
+    /**
+     * The asset of highest value, comparing `value()` so that assets of any symbol can be compared
+     *
+     * Values are compared as `f64`, so amounts that differ beyond its 53 bits of precision may tie,
+     * in which case the last one is returned
+     *
+     * @param assets - The assets to compare
+     * @return asset - or `None` if `assets` is empty
+     */
+    #[must_use]
+    pub fn max_by_value(assets: &[Asset]) -> Option<&Asset> {
+        assets.iter().max_by(|a, b| a.value().total_cmp(&b.value()))
+    }
+
+    /**
+     * The asset of lowest value, comparing `value()` so that assets of any symbol can be compared
+     *
+     * Values are compared as `f64`, so amounts that differ beyond its 53 bits of precision may tie,
+     * in which case the first one is returned
+     *
+     * @param assets - The assets to compare
+     * @return asset - or `None` if `assets` is empty
+     */
+    #[must_use]
+    pub fn min_by_value(assets: &[Asset]) -> Option<&Asset> {
+        assets.iter().min_by(|a, b| a.value().total_cmp(&b.value()))
+    }
+
+    /**
+     * Denominations of a symbol, i.e. 1, 10, 100, ... of its smallest unit, e.g. for UI step controls
+     *
+     * @param symbol - The symbol of the assets
+     * @return iterator - of increasing powers of ten, up to `MAX_AMOUNT`
+     */
+    pub fn denominations(symbol: Symbol) -> impl Iterator<Item = Asset> {
+        std::iter::successors(Some(1_i64), |&amount| amount.checked_mul(10).filter(|&a| a <= Asset::MAX_AMOUNT))
+            .map(move |amount| Asset::from_amount(amount, symbol))
+    }
+
+    /**
+     * Integer square root of the product of two amounts, e.g. `sqrt(reserve_a * reserve_b)` of an AMM pool
+     *
+     * Symbols are ignored since the result is a scalar, the amounts are taken in their own precision
+     *
+     * @param a - The first asset
+     * @param b - The second asset
+     * @return root - floor of the square root, or `None` if either amount is negative
+     */
+    #[must_use]
+    pub fn integer_sqrt_product(a: &Asset, b: &Asset) -> Option<i128> {
+        if a.amount < 0 || b.amount < 0 {
+            return None;
+        }
+        let product = a.amount as u128 * b.amount as u128;
+        Some(product.isqrt() as i128)
+    }
+
+    /**
+     * Percentage of this asset in another one, e.g. of a transfer in the supply
+     *
+     * @param whole - The asset to compare with, of the same symbol
+     * @return percentage - or `None` if the symbols differ or `whole` is zero
+     */
+    #[must_use]
+    pub fn percentage_of(&self, whole: &Asset) -> Option<f64> {
+        if self.symbol != whole.symbol || whole.amount == 0 {
+            return None;
+        }
+        Some(self.amount as f64 / whole.amount as f64 * 100.0)
+    }
+
+    /**
+     * Converts the amount into the little-endian hex string of its raw bytes, as in the
+     * hex rows returned by `get_table_rows`
+     *
+     * @return string - 16 lowercase hex digits, e.g. "1027000000000000" for an amount of 10000
+     */
+    #[must_use]
+    pub fn amount_hex_le(&self) -> String {
+        self.amount.to_le_bytes().iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    /**
+     * Construct an asset from the little-endian hex string of its amount
+     *
+     * @param hex - 16 hex digits, e.g. "1027000000000000"
+     * @param symbol - The symbol of the asset
+     * @return asset - or `BadAmount` if `hex` is malformed, `OutOfRange` if the amount exceeds the valid range
+     */
+    pub fn from_amount_hex_le(hex: &str, symbol: Symbol) -> Result<Asset, ParseError> {
+        if hex.len() != 16 || !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ParseError::BadAmount {
+                input: hex.to_string(),
+                position: None,
+            });
+        }
+        let mut bytes = [0u8; 8];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|_| ParseError::BadAmount {
+                input: hex.to_string(),
+                position: None,
+            })?;
+        }
+        let asset = Asset::from_amount(i64::from_le_bytes(bytes), symbol);
+        if !asset.is_amount_within_range() {
+            return Err(ParseError::OutOfRange);
+        }
+        Ok(asset)
+    }
+
+    /**
+     * Converts the asset into string, grouping the digits of the whole part by thousands
+     *
+     * The sign and the fractional digits are kept as is; the default `Display` is not affected
+     *
+     * @param sep - Separator inserted between groups, e.g. ',' for "1,234,567.0000 EOS"
+     * @return String in the form of "1,234.5678 SYM" format
+     */
+    pub fn to_string_grouped(&self, sep: char) -> String {
+        let formatted = self.to_string();
+        let (number, code) = formatted.split_once(' ').unwrap_or((&formatted, ""));
+        let (sign, number) = match number.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", number),
+        };
+        let (whole, fraction) = match number.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (number, None),
+        };
+
+        let mut result = String::from(sign);
+        for (i, digit) in whole.chars().enumerate() {
+            if i > 0 && (whole.len() - i) % 3 == 0 {
+                result.push(sep);
+            }
+            result.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            result.push('.');
+            result.push_str(fraction);
+        }
+        result.push(' ');
+        result.push_str(code);
         result
     }
-}
 
-impl std::ops::Div<Asset> for Asset {
-    type Output = i64;
+    /**
+     * Parse Asset from string with the whole part grouped by thousands, e.g. "1,234,567.0000 EOS"
+     *
+     * The inverse of `to_string_grouped(',')`; ungrouped input is parsed as by `FromStr`
+     *
+     * @param s - The grouped string
+     * @return asset - or `BadAmount` if the groups are malformed or a comma is in the fractional part
+     */
+    pub fn parse_grouped(s: &str) -> Result<Asset, ParseError> {
+        let (amount_str, rest) = s.split_at(s.find(char::is_whitespace).unwrap_or(s.len()));
+        let bad_amount = |position| ParseError::BadAmount {
+            input: amount_str.to_string(),
+            position,
+        };
+        let (whole, fraction) = amount_str.split_at(amount_str.find('.').unwrap_or(amount_str.len()));
+        if let Some(idx) = fraction.find(',') {
+            return Err(bad_amount(Some(whole.len() + idx)));
+        }
+
+        let groups: Vec<&str> = whole.strip_prefix('-').unwrap_or(whole).split(',').collect();
+        let commas = groups.len() - 1;
+        if commas > 0 {
+            let is_group = |g: &str, len: std::ops::RangeInclusive<usize>| len.contains(&g.len()) && g.bytes().all(|c| c.is_ascii_digit());
+            if !is_group(groups[0], 1..=3) || !groups[1..].iter().all(|g| is_group(g, 3..=3)) {
+                return Err(bad_amount(None));
+            }
+        }
+
+        // with commas the whole part is all digits, so an error lies past it
+        format!("{}{}{}", whole.replace(',', ""), fraction, rest)
+            .parse::<Asset>()
+            .map_err(|err| match err {
+                ParseError::BadAmount { position, .. } => bad_amount(position.map(|p| p + commas)),
+                err => err,
+            })
+    }
 
     /**
-     * Division operator, with another asset
+     * Parse Asset from string in the accounting style, where negative amounts are parenthesized,
+     * e.g. "(100.0000) EOS" for "-100.0000 EOS"
      *
-     * @param a - The asset which amount acts as the dividend
-     * @param b - The asset which amount acts as the divisor
-     * @return int64_t - the resulted amount after the division
-     * @pre Both asset must have the same symbol
+     * Strings without parentheses are parsed as by `FromStr`
+     *
+     * @param s - The string to parse
+     * @return asset - or `BadAmount` if the parentheses are unbalanced or enclose a signed amount
      */
-    fn div(self, b: Asset) -> Self::Output {
-        assert_ne!(b.amount, 0, "divide by zero");
-        assert_eq!(self.symbol, b.symbol, "attempt to divide assets with different symbol");
-        self.amount / b.amount
+    pub fn parse_accounting(s: &str) -> Result<Asset, ParseError> {
+        let (amount_str, rest) = s.split_at(s.find(char::is_whitespace).unwrap_or(s.len()));
+        let Some(inner) = amount_str.strip_prefix('(').and_then(|a| a.strip_suffix(')')) else {
+            return s.parse();
+        };
+        let bad_amount = |position| ParseError::BadAmount {
+            input: amount_str.to_string(),
+            position,
+        };
+        if inner.starts_with(['-', '+']) {
+            return Err(bad_amount(Some(1)));
+        }
+
+        // "-" takes the place of "(", so the offsets are the same in both strings
+        format!("-{}{}", inner, rest).parse::<Asset>().map_err(|err| match err {
+            ParseError::BadAmount { position, .. } => bad_amount(position),
+            err => err,
+        })
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /**
+     * Converts the asset into string with an explicit sign, e.g. "+1.0000 EOS", for diff displays
+     *
+     * A zero amount has no sign, "0.0000 EOS", as it is neither a gain nor a loss
+     *
+     * @return String in the form of "+1.2345 SYM" or "-1.2345 SYM"
+     */
+    #[must_use]
+    pub fn to_string_signed(&self) -> String {
+        if self.amount > 0 {
+            format!("+{}", self)
+        } else {
+            self.to_string()
+        }
+    }
+
+    /**
+     * Converts the asset into string with the glyphs registered for its code, e.g. "$12.34"
+     *
+     * The sign goes before the prefix, "-$12.34"; an unregistered code gives the `Display` form
+     *
+     * @param fmt - The glyphs of each code
+     * @return String in the form of "$12.34", or "12.34 USD" if USD is not registered
+     */
+    #[must_use]
+    pub fn format_currency(&self, fmt: &CurrencyFormat) -> String {
+        let Some((prefix, suffix)) = fmt.glyphs(self.symbol.code()) else {
+            return self.to_string();
+        };
+        let formatted = self.to_string();
+        let number = formatted.split(' ').next().unwrap_or_default();
+        let (sign, digits) = match number.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", number),
+        };
+        format!("{}{}{}{}", sign, prefix, digits, suffix)
+    }
+
+    /**
+     * Parse Asset from string, checking that it is of the expected symbol, precision included
+     *
+     * Catches feeds that send e.g. "1.00 EOS" where "1.0000 EOS" is expected
+     *
+     * @param s - The string to parse
+     * @param expected - The symbol the asset must have
+     * @return asset - or `SymbolMismatch` if the parsed symbol differs
+     */
+    pub fn from_str_checked(s: &str, expected: Symbol) -> Result<Asset, ParseError> {
+        let asset: Asset = s.parse()?;
+        if asset.symbol != expected {
+            return Err(ParseError::SymbolMismatch {
+                expected,
+                found: asset.symbol,
+            });
+        }
+        Ok(asset)
+    }
+
+    /**
+     * Parse Asset from string with the symbol code before the amount, e.g. "EOS 1.0000"
+     *
+     * `FromStr` stays amount-first; the two forms are told apart by the caller, not guessed
+     *
+     * @param s - The string to parse
+     * @return asset - or `BadFormat` unless there are exactly two tokens, `BadSymbolCode` if the first is not a code
+     */
+    pub fn parse_symbol_first(s: &str) -> Result<Asset, ParseError> {
+        let mut tokens = s.split_whitespace();
+        let (Some(code), Some(amount), None) = (tokens.next(), tokens.next(), tokens.next()) else {
+            return Err(ParseError::BadFormat);
+        };
+        let code = SymbolCode::from_abi_string(code)?;
+        format!("{} {}", amount, code).parse()
+    }
+
+    /**
+     * Converts the asset into string with exactly `scale` fractional digits, whatever the precision
+     *
+     * Extra digits are rounded half-up and missing ones are padded with zeros, as `{:.scale$}` does
+     *
+     * @param scale - Number of fractional digits
+     * @return String in the form of "1.23 SYM" format for "1.2345 SYM" at scale 2
+     */
+    #[must_use]
+    pub fn to_fixed(&self, scale: u8) -> String {
+        format!("{:.*}", scale as usize, self)
+    }
+
+    /**
+     * Converts the asset into a short string for dashboards, e.g. "1.23M EOS"
+     *
+     * Values are scaled down to the largest K, M, B or T unit in which they round half-up to at
+     * least 1.00, with two fractional digits. Smaller values are rendered as by `Display`.
+     * Display-only, as the result can't be parsed back.
+     *
+     * @return String in the form of "123.45K SYM" format
+     */
+    #[must_use]
+    pub fn humanize(&self) -> String {
+        const UNITS: [(&str, u32); 4] = [("T", 12), ("B", 9), ("M", 6), ("K", 3)];
+
+        let amount = self.amount.unsigned_abs() as i128 * 100;
+        for (suffix, exponent) in UNITS {
+            let Some(divisor) = 10_i128.checked_pow(self.symbol.precision() as u32 + exponent) else {
+                continue;
+            };
+            // the value in the unit, in hundredths
+            let hundredths = Rounding::HalfUp.div(amount, divisor);
+            if hundredths >= 100 {
+                let sign = if self.amount < 0 { "-" } else { "" };
+                return format!(
+                    "{}{}.{:02}{} {}",
+                    sign,
+                    hundredths / 100,
+                    hundredths % 100,
+                    suffix,
+                    self.symbol.code()
+                );
+            }
+        }
+        self.to_string()
+    }
+
+    /**
+     * Restrict the asset amount to a range
+     *
+     * Mirrors `Ord::clamp`, but enforces that all three assets share the same symbol
+     *
+     * @param min - Lower bound of the range
+     * @param max - Upper bound of the range
+     * @return asset - `min` if below the range, `max` if above it, otherwise the asset itself
+     * @pre All assets must have the same symbol and `min <= max`
+     */
+    #[must_use]
+    pub fn clamp(self, min: Asset, max: Asset) -> Asset {
+        check(
+            self.symbol == min.symbol && self.symbol == max.symbol,
+            "attempt to clamp asset with different symbol",
+        );
+        check(min.amount <= max.amount, "clamp lower bound is greater than upper bound");
+        Asset {
+            amount: self.amount.clamp(min.amount, max.amount),
+            symbol: self.symbol,
+        }
+    }
+
+    /**
+     * Parse an asset whose amount is written in scientific notation, e.g. "1.5e2 SYM"
+     *
+     * The exponent is expanded with integer math into the given precision, so "1e4 SYM" at
+     * precision 4 has an amount of 100000000. Plain decimal amounts are accepted as well.
+     *
+     * @param s - String formatted as "<mantissa>[e|E<exponent>] SYM"
+     * @param precision - Precision of the resulting asset symbol, at most `Symbol::MAX_PRECISION`
+     * @return asset - or `BadPrecision` above it, `BadAmount` if the value is not exactly representable at `precision`,
+     * `OutOfRange` if it exceeds the valid range
+     */
+    pub fn parse_scientific(s: &str, precision: u8) -> Result<Asset, ParseError> {
+        let parts: Vec<&str> = s.split(' ').collect();
+        if parts.len() != 2 {
+            return Err(ParseError::BadFormat);
+        }
+        let (amount_str, symbol_str) = (parts[0], parts[1]);
+        if precision > Symbol::MAX_PRECISION {
+            return Err(ParseError::BadPrecision(precision.to_string()));
+        }
+        let bad_amount = || ParseError::BadAmount {
+            input: amount_str.to_string(),
+            position: None,
+        };
+
+        let (mantissa, exponent) = match amount_str.find(['e', 'E']) {
+            Some(idx) => (&amount_str[..idx], amount_str[idx + 1..].parse::<i32>().map_err(|_| bad_amount())?),
+            None => (amount_str, 0),
+        };
+        if mantissa.matches('.').count() > 1 {
+            return Err(bad_amount());
+        }
+        let fraction_digits = match mantissa.find('.') {
+            Some(idx) => (mantissa.len() - idx - 1) as i32,
+            None => 0,
+        };
+        let digits = mantissa.replace('.', "").parse::<i128>().map_err(|_| bad_amount())?;
+
+        // amount = digits * 10^(exponent - fraction_digits + precision)
+        let shift = exponent.saturating_sub(fraction_digits).saturating_add(precision as i32);
+        let amount = match 10_i128.checked_pow(shift.unsigned_abs()) {
+            _ if digits == 0 => 0,
+            Some(factor) if shift >= 0 => digits.checked_mul(factor).ok_or(ParseError::OutOfRange)?,
+            Some(factor) if digits % factor == 0 => digits / factor,
+            None if shift >= 0 => return Err(ParseError::OutOfRange),
+            _ => return Err(bad_amount()),
+        };
+        try_check(
+            (Asset::MIN_AMOUNT as i128..=Asset::MAX_AMOUNT as i128).contains(&amount),
+            ParseError::OutOfRange,
+        )?;
+
+        let sym_code = symbol_str
+            .parse::<SymbolCode>()
+            .map_err(|_| ParseError::BadSymbolCode(symbol_str.to_string()))?;
+
+        Ok(Asset {
+            amount: amount as i64,
+            symbol: Symbol::from_precision(sym_code, precision),
+        })
+    }
+
+    /**
+     * Construct an asset from its separate components, e.g. a database row
+     *
+     * Unlike `from_amount`, every component is validated
+     *
+     * @param amount - The amount of the asset
+     * @param precision - Precision of the asset symbol, at most `Symbol::MAX_PRECISION`
+     * @param code - Symbol code, e.g. "EOS"
+     * @return asset - or `BadSymbolCode`, `BadPrecision` or `BadAmount` for the invalid component
+     */
+    pub fn try_from_parts(amount: i64, precision: u8, code: &str) -> Result<Asset, ParseError> {
+        let sym_code = code
+            .parse::<SymbolCode>()
+            .ok()
+            .filter(SymbolCode::is_valid)
+            .ok_or_else(|| ParseError::BadSymbolCode(code.to_string()))?;
+        if precision > Symbol::MAX_PRECISION {
+            return Err(ParseError::BadPrecision(precision.to_string()));
+        }
+        let asset = Asset {
+            amount,
+            symbol: Symbol::from_precision(sym_code, precision),
+        };
+        if !asset.is_amount_within_range() {
+            return Err(ParseError::BadAmount {
+                input: amount.to_string(),
+                position: None,
+            });
+        }
+        Ok(asset)
+    }
+
+    /**
+     * Construct an asset from a decimal string of any precision, e.g. "1.23456" from a price oracle
+     *
+     * The digits beyond the precision of the symbol are rounded with `mode`, without going
+     * through `f64`, so "1.23456" at precision 4 is "1.2346" half-up and "1.2345" toward zero
+     *
+     * @param s - The decimal amount, with an optional sign and no symbol code
+     * @param symbol - The symbol of the asset
+     * @param mode - How the digits beyond the precision are rounded
+     * @return asset - or `BadAmount` if `s` is not a decimal number, `OutOfRange` if it exceeds the valid range
+     */
+    pub fn from_decimal_str(s: &str, symbol: Symbol, mode: Rounding) -> Result<Asset, ParseError> {
+        let bad_amount = || ParseError::BadAmount {
+            input: s.to_string(),
+            position: invalid_amount_position(s),
+        };
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if (whole.is_empty() && fraction.is_empty()) || !whole.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit()) {
+            return Err(bad_amount());
+        }
+
+        let precision = symbol.precision() as usize;
+        let (kept, extra) = fraction.split_at(fraction.len().min(precision));
+        let digits = format!("{}{:0<precision$}", whole, kept);
+        let units = if digits.is_empty() {
+            0
+        } else {
+            digits.parse::<i128>().map_err(|_| ParseError::OutOfRange)?
+        };
+
+        // past 18 extra digits only whether the rest is zero matters, which one more digit keeps
+        let (head, tail) = extra.split_at(extra.len().min(18));
+        let mut remainder = head.parse::<i128>().unwrap_or(0);
+        let mut divisor = 10_i128.pow(head.len() as u32);
+        if tail.bytes().any(|b| b != b'0') {
+            remainder = remainder * 10 + 1;
+            divisor *= 10;
+        }
+        let scaled = units
+            .checked_mul(divisor)
+            .and_then(|n| n.checked_add(remainder))
+            .ok_or(ParseError::OutOfRange)?;
+        let amount = mode.div(if negative { -scaled } else { scaled }, divisor);
+        try_check(amount.abs() <= Asset::MAX_AMOUNT as i128, ParseError::OutOfRange)?;
+        Ok(Asset::from_amount(amount as i64, symbol))
+    }
+
+    /**
+     * Construct an asset from a float value, e.g. a price feed
+     *
+     * The value is scaled to the precision of the symbol and rounded half to even, so
+     * `0.00005` at precision 4 becomes `0.0000`
+     *
+     * @param value - The value of the asset, e.g. `1.5` for "1.5000 EOS"
+     * @param symbol - The symbol of the asset
+     * @return asset - or `BadAmount` if `value` is NaN or infinite, `OutOfRange` if it exceeds the valid range
+     */
+    pub fn try_from_f64(value: f64, symbol: Symbol) -> Result<Asset, ParseError> {
+        if !value.is_finite() {
+            return Err(ParseError::BadAmount {
+                input: value.to_string(),
+                position: None,
+            });
+        }
+        let amount = (value * 10_f64.powi(symbol.precision() as i32)).round_ties_even();
+        // MAX_AMOUNT rounds up to 2^62 as f64, so any whole value below it is within range
+        if amount.abs() >= Asset::MAX_AMOUNT as f64 {
+            return Err(ParseError::OutOfRange);
+        }
+        Ok(Asset::from_amount(amount as i64, symbol))
+    }
+
+    /**
+     * Split the asset among buckets proportionally to their weights, without losing units
+     *
+     * Uses the largest-remainder method: each bucket gets the truncated share, and the units
+     * left over go one each to the buckets with the largest remainders (the first bucket on ties).
+     * The outputs always sum up to the asset amount.
+     *
+     * @param weights - The weight of each bucket
+     * @return assets - One asset per weight, all with the symbol of this asset
+     * @pre The sum of `weights` must be greater than zero
+     */
+    #[must_use]
+    pub fn split_proportionally(&self, weights: &[u64]) -> Vec<Asset> {
+        let total: u128 = weights.iter().map(|w| *w as u128).sum();
+        check(total > 0, "split weights must sum to more than zero");
+
+        let amount = self.amount.unsigned_abs() as u128;
+        let mut shares: Vec<u128> = weights.iter().map(|w| amount * *w as u128 / total).collect();
+        let mut order: Vec<usize> = (0..weights.len()).collect();
+        // stable sort, so equal remainders keep the bucket order
+        order.sort_by_key(|i| std::cmp::Reverse(amount * weights[*i] as u128 % total));
+
+        let leftover = amount - shares.iter().sum::<u128>();
+        for i in order.into_iter().take(leftover as usize) {
+            shares[i] += 1;
+        }
+        let sign = self.amount.signum();
+        shares
+            .into_iter()
+            .map(|share| Asset::from_amount(share as i64 * sign, self.symbol))
+            .collect()
+    }
+
+    /**
+     * Division with a number, rounding the result with the given mode
+     *
+     * The `/` operator truncates toward zero, which is `Rounding::TowardZero` here
+     *
+     * @param a - The divisor for the asset's amount
+     * @param mode - How to round a result between two amounts
+     * @return asset - New asset as the result of division
+     */
+    #[must_use]
+    pub fn div_rounded(self, a: i64, mode: Rounding) -> Asset {
+        check(a != 0, "divide by zero");
+        let amount = mode.div(self.amount as i128, a as i128);
+        check(
+            (Asset::MIN_AMOUNT as i128..=Asset::MAX_AMOUNT as i128).contains(&amount),
+            "division overflow",
+        );
+        Asset {
+            amount: amount as i64,
+            symbol: self.symbol,
+        }
+    }
+
+    /**
+     * Checked addition, the non-panicking counterpart of the `+` operator
+     *
+     * @param other - The asset to add
+     * @return asset - or `None` if the symbols differ or the result is out of range
+     */
+    #[inline]
+    #[must_use]
+    pub fn checked_add(self, other: Asset) -> Option<Asset> {
+        if self.symbol != other.symbol {
+            return None;
+        }
+        self.amount
+            .checked_add(other.amount)
+            .map(|amount| Asset::from_amount(amount, self.symbol))
+            .filter(Asset::is_amount_within_range)
+    }
+
+    /**
+     * Checked subtraction, the non-panicking counterpart of the `-` operator
+     *
+     * @param other - The asset to subtract
+     * @return asset - or `None` if the symbols differ or the result is out of range
+     */
+    #[inline]
+    #[must_use]
+    pub fn checked_sub(self, other: Asset) -> Option<Asset> {
+        if self.symbol != other.symbol {
+            return None;
+        }
+        self.amount
+            .checked_sub(other.amount)
+            .map(|amount| Asset::from_amount(amount, self.symbol))
+            .filter(Asset::is_amount_within_range)
+    }
+
+    /**
+     * Checked addition assignment, the non-panicking counterpart of the `+=` operator
+     *
+     * @param other - The asset to add
+     * @return `SymbolMismatch` if the symbols differ, `OutOfRange` if the result is out of range
+     * @post On error, this asset is left unchanged
+     */
+    pub fn add_assign_checked(&mut self, other: Asset) -> Result<(), ParseError> {
+        *self = self.checked_assign_operand(other, Asset::checked_add)?;
+        Ok(())
+    }
+
+    /**
+     * Checked subtraction assignment, the non-panicking counterpart of the `-=` operator
+     *
+     * @param other - The asset to subtract
+     * @return `SymbolMismatch` if the symbols differ, `OutOfRange` if the result is out of range
+     * @post On error, this asset is left unchanged
+     */
+    pub fn sub_assign_checked(&mut self, other: Asset) -> Result<(), ParseError> {
+        *self = self.checked_assign_operand(other, Asset::checked_sub)?;
+        Ok(())
+    }
+
+    fn checked_assign_operand(self, other: Asset, op: fn(Asset, Asset) -> Option<Asset>) -> Result<Asset, ParseError> {
+        self.check_same_symbol(&other)?;
+        op(self, other).ok_or(ParseError::OutOfRange)
+    }
+
+    fn check_same_symbol(&self, other: &Asset) -> Result<(), ParseError> {
+        try_check(
+            self.symbol == other.symbol,
+            ParseError::SymbolMismatch {
+                expected: self.symbol,
+                found: other.symbol,
+            },
+        )
+    }
+
+    /**
+     * Checked comparison, the non-panicking counterpart of `Ord::cmp`
+     *
+     * @param other - The asset to compare with
+     * @return ordering - of the amounts, or `SymbolMismatch` if the symbols differ
+     */
+    pub fn cmp_checked(&self, other: &Asset) -> Result<std::cmp::Ordering, ParseError> {
+        self.check_same_symbol(other)?;
+        Ok(self.amount.cmp(&other.amount))
+    }
+
+    /**
+     * Checked multiplication by a number, the non-panicking counterpart of the `*` operator
+     *
+     * @param b - The multiplier for the asset's amount
+     * @return asset - or `OutOfRange` if the result is out of range
+     */
+    pub fn mul_checked(self, b: i64) -> Result<Asset, ParseError> {
+        let tmp = (self.amount as i128) * (b as i128);
+        try_check(tmp <= Asset::MAX_AMOUNT as i128, ParseError::OutOfRange)?;
+        try_check(tmp >= Asset::MIN_AMOUNT as i128, ParseError::OutOfRange)?;
+        Ok(Asset::from_amount(tmp as i64, self.symbol))
+    }
+
+    /**
+     * Multiplication by a 128-bit number, for multipliers beyond `i64` when the result still fits
+     *
+     * Not an operator impl, as a second integer `Mul` would make `asset * 2` ambiguous
+     *
+     * @param b - The multiplier for the asset's amount
+     * @return asset - New asset as the result of multiplication
+     */
+    #[must_use]
+    pub fn mul_i128(self, b: i128) -> Asset {
+        let negative = (self.amount < 0) != (b < 0);
+        let tmp = (self.amount as i128).checked_mul(b);
+        check(tmp.is_some() || negative, "multiplication overflow");
+        check(tmp.is_some(), "multiplication underflow");
+        let tmp = tmp.unwrap_or_default();
+        check(tmp <= Asset::MAX_AMOUNT as i128, "multiplication overflow");
+        check(tmp >= Asset::MIN_AMOUNT as i128, "multiplication underflow");
+        Asset::from_amount(tmp as i64, self.symbol)
+    }
+
+    /**
+     * Checked division by a number, the non-panicking counterpart of the `/` operator
+     *
+     * The result is truncated toward zero
+     *
+     * @param b - The divisor for the asset's amount
+     * @return asset - or `DivisionByZero` if `b` is zero, `OutOfRange` if the division overflows
+     */
+    pub fn div_checked(self, b: i64) -> Result<Asset, ParseError> {
+        try_check(b != 0, ParseError::DivisionByZero)?;
+        let amount = self.amount.checked_div(b).ok_or(ParseError::OutOfRange)?;
+        Ok(Asset::from_amount(amount, self.symbol))
+    }
+
+    /**
+     * Checked negation, the non-panicking counterpart of the unary `-` operator
+     *
+     * @return asset - or `None` if the result is out of range, e.g. for an amount of `i64::MIN`
+     */
+    #[inline]
+    #[must_use]
+    pub fn checked_neg(self) -> Option<Asset> {
+        self.amount
+            .checked_neg()
+            .map(|amount| Asset::from_amount(amount, self.symbol))
+            .filter(Asset::is_amount_within_range)
+    }
+}
+
+/**
+ * Rescale an amount from one precision to another
+ *
+ * Multiplies or divides by the power-of-ten delta in `i128`. Downshifting truncates toward zero,
+ * e.g. 1.2345 at precision 4 becomes 1.23 at precision 2
+ *
+ * @param amount - The amount at `from_prec`
+ * @param from_prec - Precision of `amount`
+ * @param to_prec - Precision of the result
+ * @return amount - or `OutOfRange` if the result exceeds the valid asset range
+ */
+pub(crate) fn shift_amount(amount: i64, from_prec: u8, to_prec: u8) -> Result<i64, ParseError> {
+    let delta = to_prec as i32 - from_prec as i32;
+    let shifted = match 10_i128.checked_pow(delta.unsigned_abs()) {
+        Some(factor) if delta >= 0 => (amount as i128).checked_mul(factor),
+        Some(factor) => Some(amount as i128 / factor),
+        // the divisor exceeds any i64, or the multiplier overflows unless the amount is zero
+        None if delta < 0 || amount == 0 => Some(0),
+        None => None,
+    };
+    shifted
+        .filter(|amount| (Asset::MIN_AMOUNT as i128..=Asset::MAX_AMOUNT as i128).contains(amount))
+        .map(|amount| amount as i64)
+        .ok_or(ParseError::OutOfRange)
+}
+
+/**
+ * Sort assets by symbol (raw value) and then by amount
+ *
+ * Unlike `sort()`, this never panics on mixed symbols, so it can order e.g. a portfolio
+ *
+ * @param v - The assets to sort in place, a `&mut Vec<Asset>` coerces to it
+ */
+pub fn sort_assets(v: &mut [Asset]) {
+    v.sort_by_key(|asset| (asset.symbol.raw(), asset.amount));
+}
+
+/**
+ * Compute the weighted average of assets, e.g. the average price of several fills
+ *
+ * The average is `sum(amount_i * weight_i) / sum(weight_i)`, computed in `i128` and truncated
+ * toward zero
+ *
+ * @param values - Pairs of an asset and its weight
+ * @return asset - or `None` if `values` is empty, the total weight is zero, the symbols differ
+ * or the computation overflows
+ */
+#[must_use]
+pub fn weighted_average(values: &[(Asset, u64)]) -> Option<Asset> {
+    let symbol = values.first()?.0.symbol;
+    let mut total: i128 = 0;
+    let mut total_weight: i128 = 0;
+    for (asset, weight) in values {
+        if asset.symbol != symbol {
+            return None;
+        }
+        total = total.checked_add((asset.amount as i128).checked_mul(*weight as i128)?)?;
+        total_weight = total_weight.checked_add(*weight as i128)?;
+    }
+    if total_weight == 0 {
+        return None;
+    }
+    let amount = i64::try_from(total / total_weight).ok()?;
+    Some(Asset::from_amount(amount, symbol))
+}
+
+/**
+ * Byte offset of the first character that can't be part of an amount, if any
+ *
+ * An amount is an optional sign followed by digits and dots; without such a character the
+ * amount is malformed as a whole, e.g. it is empty or overflows
+ */
+fn invalid_amount_position(s: &str) -> Option<usize> {
+    s.char_indices()
+        .find(|&(i, c)| !(c.is_ascii_digit() || c == '.' || (i == 0 && (c == '-' || c == '+'))))
+        .map(|(i, _)| i)
+}
+
+impl std::fmt::Display for Asset {
+    /**
+     * Converts the asset into string
+     *
+     * The formatter precision, e.g. `{:.2}`, overrides the number of fractional digits: the
+     * amount is rounded half-up when it has fewer digits, and zero-padded when it has more
+     *
+     * @return String in the form of "1.2345 SYM" format, where SYM symbol has precision equal to 4
+     */
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let symbol_precision = self.symbol.precision() as usize;
+        let (amount, precision) = match f.precision() {
+            Some(precision) if precision < symbol_precision => {
+                let shift = (symbol_precision - precision) as u32;
+                // a divisor beyond i128 rounds any i64 amount to zero
+                let amount = 10_i128
+                    .checked_pow(shift)
+                    .map_or(0, |factor| Rounding::HalfUp.div(self.amount as i128, factor));
+                (amount, precision)
+            }
+            _ => (self.amount as i128, symbol_precision),
+        };
+        let padding = f.precision().unwrap_or(precision).saturating_sub(precision);
+
+        // the sign is written separately, as the whole part of e.g. "-0.0001" is zero
+        let sign = if amount < 0 { "-" } else { "" };
+        let abs = amount.unsigned_abs();
+        if precision == 0 && padding == 0 {
+            return write!(f, "{}{} {}", sign, abs, self.symbol.code());
+        }
+
+        // digits are written straight to the formatter, a factor beyond u128 leaves a zero whole part
+        let (whole, decimal) = match 10_u128.checked_pow(precision as u32) {
+            Some(factor) => (abs / factor, abs % factor),
+            None => (0, abs),
+        };
+        write!(f, "{}{}.", sign, whole)?;
+        if precision > 0 {
+            write!(f, "{:0>precision$}", decimal)?;
+        }
+        write!(f, "{:0<padding$} {}", "", self.symbol.code())
+    }
+}
+
+impl std::fmt::Debug for Asset {
+    /**
+     * Formats the asset as `Asset("1.0000 EOS")`, which reads better in logs than its fields
+     */
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("Asset").field(&self.to_string()).finish()
+    }
+}
+
+impl std::fmt::LowerHex for Asset {
+    /**
+     * Formats the 16 packed bytes as lowercase hex, for debugging wire data
+     */
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for byte in self.to_packed() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::UpperHex for Asset {
+    /**
+     * Formats the 16 packed bytes as uppercase hex, for debugging wire data
+     */
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for byte in self.to_packed() {
+            write!(f, "{:02X}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for Asset {
+    /**
+     * Parse Asset from string formatted as "1.2345 SYM@contract"
+     *
+     */
+    fn from(s: &str) -> Self {
+        Self::from_str(s).unwrap_or_else(|e| panic!("failed to parse asset from string: {}", e))
+    }
+}
+
+impl FromStr for Asset {
+    type Err = ParseError;
+
+    /**
+     * Parse Asset from string formatted as "1.2345 SYM"
+     *
+     * The separator can be any single Unicode space, e.g. a non-breaking space (U+00A0) or a
+     * narrow no-break space (U+202F) from a copy-pasted string. Tabs and line breaks are rejected.
+     *
+     * A dot must be followed by fractional digits: "1. EOS" is rejected, as by nodeos, rather than
+     * read as precision 0
+     */
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(|c: char| c.is_whitespace() && !c.is_control()).collect();
+        if parts.len() != 2 {
+            return Err(ParseError::BadFormat);
+        }
+        let (amount_str, symbol_str) = (parts[0], parts[1]);
+        // an empty code would parse as the empty symbol code
+        if symbol_str.is_empty() {
+            return Err(ParseError::BadSymbolCode(String::new()));
+        }
+        let precision = match amount_str.find('.') {
+            Some(idx) if idx == amount_str.len() - 1 => {
+                return Err(ParseError::BadAmount {
+                    input: amount_str.to_string(),
+                    position: Some(idx),
+                })
+            }
+            Some(idx) => amount_str.len() - idx - 1,
+            None => 0,
+        };
+        let amount = match amount_str.replace('.', "").parse::<i64>() {
+            Ok(amount) => amount,
+            Err(_) => {
+                return Err(ParseError::BadAmount {
+                    input: amount_str.to_string(),
+                    position: invalid_amount_position(amount_str),
+                })
+            }
+        };
+        let sym_code = symbol_str
+            .parse::<SymbolCode>()
+            .map_err(|_| ParseError::BadSymbolCode(symbol_str.to_string()))?;
+        if precision > Symbol::MAX_PRECISION as usize {
+            return Err(ParseError::BadPrecision(precision.to_string()));
+        }
+        let symbol = Symbol::from_precision(sym_code, precision as u8);
+
+        Ok(Asset { amount, symbol })
+    }
+}
+
+impl AsRef<Asset> for Asset {
+    #[inline]
+    fn as_ref(&self) -> &Asset {
+        self
+    }
+}
+
+impl AsRef<Symbol> for Asset {
+    #[inline]
+    fn as_ref(&self) -> &Symbol {
+        &self.symbol
+    }
+}
+
+impl AsRef<i64> for Asset {
+    /**
+     * Borrow the raw amount, in the smallest unit
+     */
+    #[inline]
+    fn as_ref(&self) -> &i64 {
+        &self.amount
+    }
+}
+
+impl std::ops::Neg for Asset {
+    type Output = Asset;
+    /**
+     * Negate the amount of the asset
+     *
+     * @return a new asset with the negated amount
+     */
+    fn neg(self) -> Asset {
+        Asset {
+            amount: -self.amount,
+            symbol: self.symbol,
+        }
+    }
+}
+
+impl std::cmp::PartialEq for Asset {
+    /**
+     * Compare two assets of the same symbol
+     *
+     * As in the CDT, comparing assets of different symbols panics, which also happens inside
+     * `assert_eq!` or a `HashMap` lookup. With the `non_panicking_eq` feature they are unequal
+     * instead, trading CDT fidelity for safety; without it, `nominal_eq` and `cmp_checked` compare
+     * without panicking. Ordering operators panic on a mismatch either way.
+     */
+    fn eq(&self, other: &Asset) -> bool {
+        if cfg!(feature = "non_panicking_eq") {
+            return self.symbol == other.symbol && self.amount == other.amount;
+        }
+        check(
+            self.symbol == other.symbol,
+            "comparison of assets with different symbols is not allowed",
+        );
+        self.amount == other.amount
+    }
+}
+
+impl std::cmp::PartialEq<&str> for Asset {
+    /**
+     * Compare the asset with its string form, e.g. `asset == "1.0000 EOS"`
+     *
+     * Unlike comparing two assets this never panics: a malformed string or a different symbol
+     * makes the comparison false
+     */
+    fn eq(&self, other: &&str) -> bool {
+        other
+            .parse::<Asset>()
+            .is_ok_and(|other| self.symbol == other.symbol && self.amount == other.amount)
+    }
+}
+
+impl std::cmp::PartialOrd for Asset {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::cmp::Ord for Asset {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        check(
+            self.symbol == other.symbol,
+            "comparison of assets with different symbols is not allowed",
+        );
+
+        self.amount.cmp(&other.amount)
+    }
+}
+
+impl std::cmp::PartialEq<i64> for Asset {
+    /**
+     * Compare the amount in the smallest unit with a number, e.g. `asset == 10000`
+     *
+     * The number is not scaled by the precision, so `1.0000 EOS == 10000`, not `1`; the symbol is ignored
+     */
+    fn eq(&self, other: &i64) -> bool {
+        self.amount == *other
+    }
+}
+
+impl std::cmp::PartialOrd<i64> for Asset {
+    /**
+     * Compare the amount in the smallest unit with a number, e.g. `asset > 0`
+     *
+     * The number is not scaled by the precision and the symbol is ignored, as for `PartialEq<i64>`
+     */
+    fn partial_cmp(&self, other: &i64) -> Option<std::cmp::Ordering> {
+        self.amount.partial_cmp(other)
+    }
+}
+
+impl std::ops::SubAssign for Asset {
+    /**
+     * Subtraction assignment operator
+     *
+     * @param other - Another asset to subtract this asset with
+     * @post The amount of this asset is subtracted by the amount of asset `other`
+     */
+    fn sub_assign(&mut self, other: Asset) {
+        assert_eq!(self.symbol, other.symbol, "attempt to subtract asset with different symbol");
+        self.amount -= other.amount;
+        check(Asset::MIN_AMOUNT <= self.amount, "subtraction underflow");
+        check(self.amount <= Asset::MAX_AMOUNT, "subtraction overflow");
+    }
+}
+
+impl std::ops::AddAssign for Asset {
+    /**
+     * Addition Assignment  operator
+     *
+     * @param a - Another asset to add with this asset
+     * @post The amount of this asset is added with the amount of asset a
+     */
+    fn add_assign(&mut self, a: Self) {
+        assert_eq!(self.symbol, a.symbol, "attempt to add asset with different symbol");
+        self.amount += a.amount;
+        assert!(Self::MIN_AMOUNT <= self.amount, "addition underflow");
+        assert!(self.amount <= Self::MAX_AMOUNT, "addition overflow");
+    }
+}
+
+impl std::ops::MulAssign<i64> for Asset {
+    /**
+     * Multiplication assignment operator, with a number
+     *
+     * @details Multiplication assignment operator. Multiply the amount of this asset with a number and then assign the value to itself.
+     * @param a - The multiplier for the asset's amount
+     * @return asset - Reference to this asset
+     * @post The amount of this asset is multiplied by a
+     */
+    fn mul_assign(&mut self, a: i64) {
+        let tmp = (self.amount as i128) * (a as i128);
+        assert!(tmp <= Self::MAX_AMOUNT as i128, "multiplication overflow");
+        assert!(tmp >= Self::MIN_AMOUNT as i128, "multiplication underflow");
+        self.amount = tmp as i64;
+    }
+}
+
+impl std::ops::DivAssign<i64> for Asset {
+    /**
+     * Division assignment operator, with a number proceeding
+     *
+     * @brief Division assignment operator, with a number proceeding
+     * @details The result is truncated toward zero
+     * @param self - The asset to be divided
+     * @param a - The divisor for the asset's amount
+     * @return asset - Reference to the asset, which has been divided
+     */
+    fn div_assign(&mut self, a: i64) {
+        check(a != 0, "divide by zero");
+        check(!(self.amount == i64::MIN && a == -1), "signed division overflow");
+        self.amount /= a;
+    }
+}
+
+impl std::ops::Add for Asset {
+    type Output = Self;
+
+    /**
+     * Addition operator
+     *
+     * @param other - The second asset to be added to the first asset
+     * @return asset - New asset as the result of addition
+     */
+    fn add(self, other: Self) -> Self {
+        let mut result = self;
+        result += other;
+        result
+    }
+}
+
+impl std::iter::Sum for Asset {
+    /**
+     * Sum the assets with the addition operator, which checks the symbols and the range
+     *
+     * @return asset - The total, or an empty asset if there are no assets
+     */
+    fn sum<I: Iterator<Item = Asset>>(iter: I) -> Self {
+        iter.reduce(|total, a| total + a).unwrap_or_default()
+    }
+}
+
+impl<'a> std::iter::Sum<&'a Asset> for Asset {
+    /**
+     * Sum the borrowed assets without cloning the collection
+     *
+     * @return asset - The total, or an empty asset if there are no assets
+     */
+    fn sum<I: Iterator<Item = &'a Asset>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl std::ops::Sub for Asset {
+    type Output = Self;
+
+    /**
+     * Subtraction operator
+     *
+     * @param other - The asset used to subtract from the first asset
+     * @return asset - New asset as the result of subtraction
+     */
+    fn sub(self, other: Self) -> Self {
+        let mut result = self;
+        result -= other;
+        result
+    }
+}
+
+impl std::ops::Mul<i64> for Asset {
+    type Output = Asset;
+
+    /**
+     * Multiplication operator, with a number proceeding
+     *
+     * @brief Multiplication operator, with a number proceeding
+     * @param a - The asset to be multiplied
+     * @param b - The multiplier for the asset's amount
+     * @return asset - New asset as the result of multiplication
+     */
+    fn mul(self, b: i64) -> Asset {
+        let mut result = self;
+        result *= b;
+        result
+    }
+}
+
+impl std::ops::Mul<Ratio> for Asset {
+    type Output = Asset;
+
+    /**
+     * Multiplication operator, with a ratio
+     *
+     * @details The product is computed in 128 bits and truncated toward zero, as the division operator does
+     * @param ratio - The ratio to multiply the asset's amount by, e.g. 3/100 for a 3% fee
+     * @return asset - New asset as the result of multiplication
+     */
+    fn mul(self, ratio: Ratio) -> Asset {
+        check(ratio.den != 0, "divide by zero");
+        let tmp = (self.amount as i128) * (ratio.num as i128) / (ratio.den as i128);
+        check(tmp <= Asset::MAX_AMOUNT as i128, "multiplication overflow");
+        check(tmp >= Asset::MIN_AMOUNT as i128, "multiplication underflow");
+        Asset {
+            amount: tmp as i64,
+            symbol: self.symbol,
+        }
+    }
+}
+
+impl std::ops::Mul<Asset> for i64 {
+    type Output = Asset;
+
+    /**
+     * Multiplication operator, with a number preceeding
+     *
+     * @param a - The multiplier for the asset's amount
+     * @param b - The asset to be multiplied
+     * @return asset - New asset as the result of multiplication
+     */
+    fn mul(self, a: Asset) -> Asset {
+        a * self
+    }
+}
+
+impl std::ops::Div<i64> for Asset {
+    type Output = Asset;
+
+    /**
+     * Division operator, with a number proceeding
+     *
+     * The result is truncated toward zero, see `div_rounded` for other rounding modes
+     *
+     * @param a - The asset to be divided
+     * @param b - The divisor for the asset's amount
+     * @return asset - New asset as the result of division
+     */
+    fn div(self, b: i64) -> Asset {
+        let mut result = self;
+        result /= b;
+        result
+    }
+}
+
+impl std::ops::Div<Asset> for Asset {
+    type Output = i64;
+
+    /**
+     * Division operator, with another asset
+     *
+     * @param a - The asset which amount acts as the dividend
+     * @param b - The asset which amount acts as the divisor
+     * @return int64_t - the resulted amount after the division
+     * @pre Both asset must have the same symbol
+     */
+    fn div(self, b: Asset) -> Self::Output {
+        assert_ne!(b.amount, 0, "divide by zero");
+        assert_eq!(self.symbol, b.symbol, "attempt to divide assets with different symbol");
+        self.amount / b.amount
+    }
+}
+
+impl std::ops::Rem<Asset> for Asset {
+    type Output = Asset;
+
+    /**
+     * Remainder operator, with another asset
+     *
+     * Complements the division operator, so that `a == (a / b) * b + (a % b)`
+     *
+     * @param a - The asset which amount acts as the dividend
+     * @param b - The asset which amount acts as the divisor
+     * @return asset - The remainder, with the sign of the dividend and the same symbol
+     * @pre Both asset must have the same symbol
+     */
+    fn rem(self, b: Asset) -> Self::Output {
+        assert_ne!(b.amount, 0, "divide by zero");
+        assert_eq!(self.symbol, b.symbol, "attempt to divide assets with different symbol");
+        Asset {
+            amount: self.amount.wrapping_rem(b.amount),
+            symbol: self.symbol,
+        }
+    }
+}
+
+impl Pack for Asset {
+    /**
+     * Packs the asset as its `i64` amount followed by its symbol
+     */
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.amount.pack(out);
+        self.symbol.pack(out);
+    }
+}
+
+impl Unpack for Asset {
+    fn unpack(data: &[u8], pos: &mut usize) -> Result<Self, ParseError> {
+        let amount = i64::unpack(data, pos)?;
+        let symbol = Symbol::unpack(data, pos)?;
+        Ok(Asset { amount, symbol })
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Asset {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Asset".into()
+    }
+
+    /// Describes the string form of an asset, e.g. "1.0000 EOS"
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": r"^-?\d+(\.\d+)? [A-Z]{1,7}$",
+        })
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::Zero for Asset {
+    /**
+     * Zero asset with an empty symbol, as the trait doesn't take one
+     *
+     * Adding it to an asset with a symbol panics like any symbol mismatch, so prefer
+     * `Asset::zero(symbol)` whenever the symbol is known
+     */
+    fn zero() -> Self {
+        Asset::new()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Asset {
+    /// Generates only valid assets, with an amount within `MIN_AMOUNT..=MAX_AMOUNT`
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let symbol = Symbol::arbitrary(u)?;
+        let amount = u.int_in_range(Asset::MIN_AMOUNT..=Asset::MAX_AMOUNT)?;
+        Ok(Asset { amount, symbol })
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for Asset {
+    /// Generates only valid assets, with an amount within `MIN_AMOUNT..=MAX_AMOUNT`
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let amount = i64::arbitrary(g) % (Asset::MAX_AMOUNT + 1);
+        Asset::from_amount(amount, Symbol::arbitrary(g))
+    }
+
+    /// Shrinks the amount toward 0 first, then the symbol toward precision 0
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let (amount, symbol) = (self.amount, self.symbol);
+        let amounts = amount.shrink().map(move |a| Asset::from_amount(a, symbol));
+        let symbols = symbol.shrink().map(move |s| Asset::from_amount(amount, s));
+        Box::new(amounts.chain(symbols))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// A symbol of a precision above `Symbol::MAX_PRECISION`, as decoded from a raw `u64`
+    fn raw_symbol(precision: u8, code: &str) -> Symbol {
+        Symbol::from((SymbolCode::from(code).raw() << 8) | precision as u64)
+    }
+
+    #[test]
+    fn test_cdt_1() {
+        assert_eq!(Asset::new().symbol.raw(), 0);
+        assert_eq!(Asset::new().amount, 0);
+    }
+
+    #[test]
+    fn test_zero() {
+        let zero = Asset::zero(Symbol::from("4,EOS"));
+        assert_eq!(zero.to_string(), "0.0000 EOS");
+        assert!(zero.is_zero());
+        assert!(Asset::from("0.0000 EOS").is_zero());
+        assert!(Asset::new().is_zero());
+        assert!(!Asset::from("-0.0001 EOS").is_zero());
+        assert_eq!(zero + Asset::from("1.0000 EOS"), Asset::from("1.0000 EOS"));
+    }
+
+    #[test]
+    fn test_zero_consts() {
+        const ZERO_EOS: Asset = Asset::zero_eos();
+        assert!(ZERO_EOS.is_zero());
+        assert_eq!(ZERO_EOS.symbol, Symbol::from("4,EOS"));
+        assert_eq!(ZERO_EOS.to_string(), "0.0000 EOS");
+        assert_eq!(Asset::zero_wax().to_string(), "0.00000000 WAX");
+        assert_eq!(Asset::zero_tlos().to_string(), "0.0000 TLOS");
+        assert_eq!(Asset::zero_usdt().to_string(), "0.0000 USDT");
+        assert_eq!(Asset::zero_sys().to_string(), "0.0000 SYS");
+        assert!(Asset::from("0.0001 EOS") > Asset::zero_eos());
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn test_num_traits_zero() {
+        let zero = <Asset as num_traits::Zero>::zero();
+        assert_eq!(zero.symbol, Symbol::new());
+        assert!(num_traits::Zero::is_zero(&zero));
+        assert!(!num_traits::Zero::is_zero(&Asset::from("1 EOS")));
+        assert_eq!(zero + Asset::new(), Asset::new());
+    }
+
+    #[test]
+    fn test_min_amount() {
+        assert_eq!(Asset::MIN_AMOUNT, -Asset::MAX_AMOUNT);
+        assert!(Asset::from_amount(Asset::MIN_AMOUNT, Symbol::from("4,SYM")).is_amount_within_range());
+        assert!(!Asset::from_amount(Asset::MIN_AMOUNT - 1, Symbol::from("4,SYM")).is_amount_within_range());
+    }
+
+    #[test]
+    fn test_asset_creation() {
+        let asset = Asset {
+            amount: 1000,
+            symbol: Symbol::from("4,SYS"),
+        };
+        assert_eq!(asset.amount, 1000);
+        assert_eq!(asset.symbol, Symbol::from("4,SYS"));
+    }
+
+    #[test]
+    fn test_asset_equality() {
+        let asset1 = Asset {
+            amount: 1000,
+            symbol: Symbol::from("4,SYS"),
+        };
+        let asset2 = Asset {
+            amount: 1000,
+            symbol: Symbol::from("4,SYS"),
+        };
+        assert_eq!(asset1, asset2);
+    }
+
+    #[test]
+    #[cfg(not(feature = "non_panicking_eq"))]
+    #[should_panic(expected = "comparison of assets with different symbols is not allowed")]
+    fn test_equality_operator_panics() {
+        let asset1 = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        let asset2 = Asset {
+            amount: 100,
+            symbol: Symbol::from("5,SYM"),
+        };
+
+        let _ = asset1 == asset2;
+    }
+
+    #[test]
+    #[cfg(feature = "non_panicking_eq")]
+    fn test_equality_operator_non_panicking() {
+        let asset1 = Asset::from("1.0000 SYM");
+        let asset2 = Asset::from("1.000 SYM");
+        assert!(asset1 != asset2);
+        assert!(asset1 != Asset::from("1.0000 EOS"));
+        assert!(Asset::new() != asset1);
+        assert_eq!(asset1, Asset::from("1.0000 SYM"));
+    }
+
+    #[test]
+    #[cfg(feature = "non_panicking_eq")]
+    #[should_panic(expected = "comparison of assets with different symbols is not allowed")]
+    fn test_ord_operator_still_panics() {
+        let _ = Asset::from("1.0000 SYM") < Asset::from("1.000 SYM");
+    }
+
+    #[test]
+    fn test_inequality_operator() {
+        let asset1 = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        let asset2 = Asset {
+            amount: 200,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        assert_ne!(asset1, asset2);
+    }
+
+    #[test]
+    #[cfg(not(feature = "non_panicking_eq"))]
+    #[should_panic(expected = "comparison of assets with different symbols is not allowed")]
+    fn test_inequality_operator_panics() {
+        let asset1 = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        let asset2 = Asset {
+            amount: 100,
+            symbol: Symbol::from("5,SYM"),
+        };
+
+        let _ = asset1 != asset2;
+    }
+
+    #[test]
+    fn test_ord_operator() {
+        let asset1 = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        let asset2 = Asset {
+            amount: 200,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        let asset3 = Asset {
+            amount: 200,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        assert!(asset1 < asset2);
+        assert!(asset2 > asset1);
+        assert!(asset1 <= asset2);
+        assert!(asset2 >= asset1);
+        assert!(asset3 >= asset2);
+        assert!(asset3 <= asset2);
+    }
+
+    #[test]
+    #[should_panic(expected = "comparison of assets with different symbols is not allowed")]
+    fn test_ord_operator_panics() {
+        let asset1 = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        let asset2 = Asset {
+            amount: 100,
+            symbol: Symbol::from("5,SYM"),
+        };
+
+        let _ = asset1 > asset2;
+    }
+
+    #[test]
+    fn test_neg() {
+        let asset = Asset::from_amount(100, Symbol::new());
+        let negated_asset = -asset;
+        assert_eq!(negated_asset.amount, -100);
+    }
+
+    #[test]
+    fn test_checked_neg() {
+        let asset = Asset::from("1.0000 EOS");
+        assert_eq!(asset.checked_neg().map(|a| a.amount), Some(-10000));
+        assert_eq!(asset.checked_neg().map(|a| a.symbol), Some(asset.symbol));
+        assert_eq!(Asset::from("-1.0000 EOS").checked_neg().map(|a| a.amount), Some(10000));
+        assert_eq!(Asset::from_amount(0, asset.symbol).checked_neg().map(|a| a.amount), Some(0));
+        assert_eq!(
+            Asset::from_amount(Asset::MAX_AMOUNT, asset.symbol).checked_neg().map(|a| a.amount),
+            Some(Asset::MIN_AMOUNT)
+        );
+        assert!(Asset::from_amount(i64::MIN, asset.symbol).checked_neg().is_none());
+        assert!(Asset::from_amount(Asset::MAX_AMOUNT + 1, asset.symbol).checked_neg().is_none());
+    }
+
+    #[test]
+    fn test_checked_add_sub() {
+        let a = Asset::from("1.0000 EOS");
+        let b = Asset::from("0.5000 EOS");
+        assert_eq!(a.checked_add(b).map(|a| a.amount), Some(15000));
+        assert_eq!(a.checked_sub(b).map(|a| a.amount), Some(5000));
+        assert!(a.checked_add(Asset::from("1.0000 FOO")).is_none());
+        assert!(a.checked_sub(Asset::from("1.000 EOS")).is_none());
+
+        let max = Asset::from_amount(Asset::MAX_AMOUNT, a.symbol);
+        let min = Asset::from_amount(Asset::MIN_AMOUNT, a.symbol);
+        assert!(max.checked_add(a).is_none());
+        assert!(min.checked_sub(a).is_none());
+        assert!(Asset::from_amount(i64::MAX, a.symbol).checked_add(max).is_none());
+        assert_eq!(max.checked_sub(max).map(|a| a.amount), Some(0));
+    }
+
+    #[test]
+    fn test_sub_assign() {
+        let mut asset1 = Asset {
+            amount: 100,
+            symbol: Symbol::new(),
+        };
+        let asset2 = Asset {
+            amount: 50,
+            symbol: Symbol::new(),
+        };
+
+        asset1 -= asset2;
+
+        assert_eq!(asset1.amount, 50);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract asset with different symbol")]
+    fn test_sub_assign_with_different_symcode() {
+        let mut asset1 = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+        let asset2 = Asset {
+            amount: 50,
+            symbol: Symbol::from("4,TST"),
+        };
+
+        asset1 -= asset2;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract asset with different symbol")]
+    fn test_sub_assign_with_different_precision() {
+        let mut asset1 = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+        let asset2 = Asset {
+            amount: 50,
+            symbol: Symbol::from("5,SYM"),
+        };
+
+        asset1 -= asset2;
+    }
+
+    #[test]
+    #[should_panic(expected = "subtraction underflow")]
+    fn test_sub_assign_overflow() {
+        let mut asset1 = Asset {
+            amount: Asset::MIN_AMOUNT,
+            symbol: Symbol::new(),
+        };
+        let asset2 = Asset {
+            amount: 1,
+            symbol: Symbol::new(),
+        };
+
+        asset1 -= asset2;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add asset with different symbol")]
+    fn test_add_assign_with_different_symcode() {
+        let mut asset1 = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+        let asset2 = Asset {
+            amount: 50,
+            symbol: Symbol::from("4,TST"),
+        };
+
+        asset1 += asset2;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add asset with different symbol")]
+    fn test_add_assign_with_different_precision() {
+        let mut asset1 = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+        let asset2 = Asset {
+            amount: 50,
+            symbol: Symbol::from("5,SYM"),
+        };
+
+        asset1 += asset2;
+    }
+
+    #[test]
+    #[should_panic(expected = "addition overflow")]
+    fn test_add_assign_overflow() {
+        let mut asset1 = Asset {
+            amount: Asset::MAX_AMOUNT,
+            symbol: Symbol::new(),
+        };
+        let asset2 = Asset {
+            amount: 1,
+            symbol: Symbol::new(),
+        };
+
+        asset1 += asset2;
+    }
+
+    #[test]
+    fn test_asset_addition() {
+        let asset_a = Asset {
+            symbol: Symbol::from("4,SYS"),
+            amount: 1000,
+        };
+        let asset_b = Asset {
+            symbol: Symbol::from("4,SYS"),
+            amount: 2000,
+        };
+
+        let result = asset_a + asset_b;
+        assert_eq!(result.symbol, Symbol::from("4,SYS"));
+        assert_eq!(result.amount, 3000);
+    }
+
+    #[test]
+    fn test_sum() {
+        let assets: Vec<Asset> = vec![Asset::from("1.0000 SYS"), Asset::from("2.5000 SYS"), Asset::from("-0.5000 SYS")];
+        let by_ref: &Vec<Asset> = &assets;
+        assert_eq!(by_ref.iter().sum::<Asset>(), Asset::from("3.0000 SYS"));
+        assert_eq!(assets.into_iter().sum::<Asset>(), Asset::from("3.0000 SYS"));
+
+        let empty = Vec::<Asset>::new().iter().sum::<Asset>();
+        assert_eq!(empty.amount, 0);
+        assert_eq!(empty.symbol, Symbol::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add asset with different symbol")]
+    fn test_sum_different_symbols() {
+        let _ = [Asset::from("1.0000 SYS"), Asset::from("1.0000 EOS")].iter().sum::<Asset>();
+    }
+
+    #[test]
+    #[should_panic(expected = "addition overflow")]
+    fn test_sum_overflow() {
+        let max = Asset::from_amount(Asset::MAX_AMOUNT, Symbol::from("4,SYS"));
+        let _ = [max, max].iter().sum::<Asset>();
+    }
+
+    #[test]
+    fn test_asset_subtraction() {
+        let asset_a = Asset {
+            symbol: Symbol::from("4,SYS"),
+            amount: 3000,
+        };
+        let asset_b = Asset {
+            symbol: Symbol::from("4,SYS"),
+            amount: 2000,
+        };
+
+        let result = asset_a - asset_b;
+        assert_eq!(result.symbol, Symbol::from("4,SYS"));
+        assert_eq!(result.amount, 1000);
+    }
+
+    #[test]
+    fn test_mul_assign() {
+        let mut asset = Asset {
+            symbol: Symbol::from("4,SYS"),
+            amount: 10,
+        };
+        asset *= 2;
+        assert_eq!(asset.amount, 20);
+        asset *= 3;
+        assert_eq!(asset.amount, 60);
+    }
+
+    #[test]
+    #[should_panic(expected = "multiplication overflow")]
+    fn test_mul_assign_overflow() {
+        let mut asset1 = Asset {
+            amount: Asset::MAX_AMOUNT,
+            symbol: Symbol::from("4,SYM"),
+        };
+        asset1 *= 2;
+    }
+
+    #[test]
+    #[should_panic(expected = "multiplication underflow")]
+    fn test_mul_assign_underflow() {
+        let mut asset1 = Asset {
+            amount: Asset::MAX_AMOUNT,
+            symbol: Symbol::from("4,SYM"),
+        };
+        asset1 *= -2;
+    }
+
+    #[test]
+    fn test_multiplication_operator() {
+        let asset1 = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        // Test positive multiplier
+        let asset2 = asset1 * 5;
+        assert_eq!(asset2.amount, 500);
+        assert_eq!(asset2.symbol, Symbol::from("4,SYM"));
+
+        // Test negative multiplier
+        let asset3 = -5 * asset1;
+        assert_eq!(asset3.amount, -500);
+        assert_eq!(asset3.symbol, Symbol::from("4,SYM"));
+    }
+
+    #[test]
+    fn test_mul_ratio() {
+        let asset = Asset::from("100.0000 EOS");
+        assert_eq!(asset * Ratio::new(3, 100), Asset::from("3.0000 EOS"));
+        assert_eq!(asset * Ratio::new(-3, 100), Asset::from("-3.0000 EOS"));
+        assert_eq!(asset * Ratio::new(1, 3), Asset::from("33.3333 EOS"));
+        assert_eq!(Asset::from("-0.0002 EOS") * Ratio::new(1, 3), Asset::from("0.0000 EOS"));
+        // the intermediate product exceeds i64 but the result doesn't
+        let max = Asset::from_amount(Asset::MAX_AMOUNT, Symbol::from("4,EOS"));
+        assert_eq!(max * Ratio::new(i64::MAX, i64::MAX), max);
+    }
+
+    #[test]
+    #[should_panic(expected = "multiplication overflow")]
+    fn test_mul_ratio_overflow() {
+        let _ = Asset::from_amount(Asset::MAX_AMOUNT, Symbol::from("4,EOS")) * Ratio::new(101, 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "multiplication underflow")]
+    fn test_mul_ratio_underflow() {
+        let _ = Asset::from_amount(Asset::MAX_AMOUNT, Symbol::from("4,EOS")) * Ratio::new(2, -1);
+    }
+
+    #[test]
+    #[should_panic(expected = "divide by zero")]
+    fn test_mul_ratio_zero_denominator() {
+        let _ = Asset::from("1.0000 EOS") * Ratio::new(1, 0);
+    }
+
+    #[test]
+    fn test_div_assign() {
+        let mut asset1 = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        asset1 /= 2;
+        assert_eq!(asset1.amount, 50);
+
+        asset1 /= -5;
+        assert_eq!(asset1.amount, -10);
+    }
+
+    #[test]
+    #[should_panic(expected = "divide by zero")]
+    fn test_asset_divide_by_zero() {
+        let mut asset1 = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        asset1 /= 0;
+    }
+
+    #[test]
+    #[should_panic(expected = "signed division overflow")]
+    fn test_asset_signed_division_overflow() {
+        let mut asset1 = Asset {
+            amount: i64::MIN,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        asset1 /= -1;
+    }
+
+    #[test]
+    fn test_divide_operator() {
+        let asset = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        let result = asset / 2;
+        assert_eq!(result.amount, 50);
+        assert_eq!(result.symbol, Symbol::from("4,SYM"));
+    }
+
+    #[test]
+    #[should_panic(expected = "divide by zero")]
+    fn test_divide_by_zero() {
+        let asset = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        let _ = asset / 0;
+    }
+
+    #[test]
+    #[should_panic(expected = "signed division overflow")]
+    fn test_signed_division_overflow() {
+        let asset = Asset {
+            amount: i64::MIN,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        let _ = asset / -1;
+    }
+
+    #[test]
+    fn test_asset_divide_asset_operator() {
+        let asset1 = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        let asset2 = Asset {
+            amount: 50,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        let result = asset1 / asset2;
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_div_rounded() {
+        let asset = Asset::from("0.0003 SYM");
+        assert_eq!((asset / 2).to_string(), "0.0001 SYM");
+        assert_eq!(asset.div_rounded(2, Rounding::TowardZero).to_string(), "0.0001 SYM");
+        assert_eq!(asset.div_rounded(2, Rounding::HalfUp).to_string(), "0.0002 SYM");
+        assert_eq!(asset.div_rounded(2, Rounding::HalfEven).to_string(), "0.0002 SYM");
+        assert_eq!((-asset).div_rounded(2, Rounding::HalfUp).to_string(), "-0.0002 SYM");
+        assert_eq!((-asset).div_rounded(2, Rounding::Floor).to_string(), "-0.0002 SYM");
+        assert_eq!(
+            Asset::from("0.0005 SYM").div_rounded(2, Rounding::HalfEven).to_string(),
+            "0.0002 SYM"
+        );
+        assert_eq!(Asset::from("0.0010 SYM").div_rounded(3, Rounding::Ceil).to_string(), "0.0004 SYM");
+    }
+
+    #[test]
+    #[should_panic(expected = "divide by zero")]
+    fn test_div_rounded_by_zero() {
+        let _ = Asset::from("0.0003 SYM").div_rounded(0, Rounding::HalfUp);
+    }
+
+    #[test]
+    fn test_asset_rem_operator() {
+        let a = Asset::from("100.0000 SYM");
+        let b = Asset::from("30.0000 SYM");
+        let r = a % b;
+        assert_eq!(r.to_string(), "10.0000 SYM");
+        assert_eq!((a / b) * b + r, a);
+
+        let a = Asset::from("-100.0000 SYM");
+        assert_eq!((a % b).to_string(), "-10.0000 SYM");
+        assert_eq!((a / b) * b + (a % b), a);
+        assert_eq!((Asset::from("90.0000 SYM") % b).amount, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide assets with different symbol")]
+    fn test_asset_rem_operator_different_symbols() {
+        let _ = Asset::from("100.0000 SYM") % Asset::from("30.000 SYM");
+    }
+
+    #[test]
+    #[should_panic(expected = "divide by zero")]
+    fn test_asset_rem_operator_divide_by_zero() {
+        let _ = Asset::from("100.0000 SYM") % Asset::from("0.0000 SYM");
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide assets with different symbol")]
+    fn test_asset_divide_asset_operator_different_symbols() {
+        let asset1 = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        let asset2 = Asset {
+            amount: 50,
+            symbol: Symbol::from("5,SYM"),
+        };
+
+        let _ = asset1 / asset2;
+    }
+
+    #[test]
+    #[should_panic(expected = "divide by zero")]
+    fn test_asset_divide_asset_operator_divide_by_zero() {
+        let asset1 = Asset {
+            amount: 100,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        let asset2 = Asset {
+            amount: 0,
+            symbol: Symbol::from("4,SYM"),
+        };
+
+        let _ = asset1 / asset2;
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(Asset::from_amount(-1000001, Symbol::from("4,SYM")).to_string(), "-100.0001 SYM");
+        assert_eq!(Asset::from_amount(10000, Symbol::from("4,SYM")).to_string(), "1.0000 SYM");
+        assert_eq!(Asset::from_amount(0, Symbol::from("4,SYM")).to_string(), "0.0000 SYM");
+        assert_eq!(Asset::from_amount(12345, Symbol::from("2,SYM")).to_string(), "123.45 SYM");
+        assert_eq!(Asset::from_amount(100, Symbol::from("0,SYM")).to_string(), "100 SYM");
+        assert_eq!(Asset::from_amount(0, Symbol::from("0,SYM")).to_string(), "0 SYM");
+        assert_eq!(Asset::from_amount(-100, Symbol::from("0,SYM")).to_string(), "-100 SYM");
+        assert_eq!(
+            Asset::from_amount(0, Symbol::from("18,SYMBOLL")).to_string(),
+            "0.000000000000000000 SYMBOLL"
+        );
+        assert_eq!(
+            Asset::from_amount(-1000000000000000000, Symbol::from("18,SYMBOLL")).to_string(),
+            "-1.000000000000000000 SYMBOLL"
+        );
+    }
+
+    #[test]
+    fn test_humanize() {
+        let eos = |amount| Asset::from_amount(amount, Symbol::eos()).humanize();
+        assert_eq!(eos(1_234_500_000), "123.45K EOS");
+        assert_eq!(eos(12_345_000_000), "1.23M EOS");
+        assert_eq!(eos(12_350_000_000), "1.24M EOS");
+        assert_eq!(eos(-1_234_500_000), "-123.45K EOS");
+        assert_eq!(eos(10_000_000), "1.00K EOS");
+        assert_eq!(eos(9_999_999), "1.00K EOS");
+        assert_eq!(eos(9_999_999_999), "1.00M EOS");
+        assert_eq!(eos(12_345_678_900_000), "1.23B EOS");
+        assert_eq!(eos(Asset::MAX_AMOUNT), "461.17T EOS");
+        assert_eq!(
+            Asset::from_amount(Asset::MAX_AMOUNT, Symbol::from("0,SYM")).humanize(),
+            "4611686.02T SYM"
+        );
+    }
+
+    #[test]
+    fn test_humanize_small() {
+        let eos = |amount| Asset::from_amount(amount, Symbol::eos()).humanize();
+        assert_eq!(eos(12_345), "1.2345 EOS");
+        assert_eq!(eos(9_949_999), "994.9999 EOS");
+        assert_eq!(eos(-1), "-0.0001 EOS");
+        assert_eq!(eos(0), "0.0000 EOS");
+        assert_eq!(
+            Asset::from_amount(i64::MAX, raw_symbol(30, "SYM")).humanize(),
+            "0.000000000009223372036854775807 SYM"
+        );
+    }
+
+    #[test]
+    fn test_to_string_grouped() {
+        assert_eq!(Asset::from("1234567.0000 EOS").to_string_grouped(','), "1,234,567.0000 EOS");
+        assert_eq!(Asset::from("-1234567.0000 EOS").to_string_grouped(','), "-1,234,567.0000 EOS");
+        assert_eq!(Asset::from("123456.0000 EOS").to_string_grouped(','), "123,456.0000 EOS");
+        assert_eq!(Asset::from("1000 EOS").to_string_grouped(' '), "1 000 EOS");
+        assert_eq!(Asset::from("999.9999 EOS").to_string_grouped(','), "999.9999 EOS");
+        assert_eq!(Asset::from("0.1234 EOS").to_string_grouped(','), "0.1234 EOS");
+        assert_eq!(Asset::from("-0.0001 EOS").to_string_grouped(','), "-0.0001 EOS");
+    }
+
+    #[test]
+    fn test_parse_grouped() {
+        assert_eq!(Asset::parse_grouped("1,234,567.0000 EOS"), Ok(Asset::from("1234567.0000 EOS")));
+        assert_eq!(Asset::parse_grouped("-1,234,567.0000 EOS"), Ok(Asset::from("-1234567.0000 EOS")));
+        assert_eq!(Asset::parse_grouped("123,456 EOS"), Ok(Asset::from("123456 EOS")));
+        assert_eq!(Asset::parse_grouped("999.9999 EOS"), Ok(Asset::from("999.9999 EOS")));
+        for s in ["1234567.0000 EOS", "-1000.0001 EOS", "0.1234 EOS"] {
+            let asset = Asset::from(s);
+            assert_eq!(Asset::parse_grouped(&asset.to_string_grouped(',')), Ok(asset));
+        }
+    }
+
+    #[test]
+    fn test_parse_accounting() {
+        assert_eq!(Asset::parse_accounting("(100.0000) EOS").map(|a| a.amount), Ok(-1_000_000));
+        assert_eq!(Asset::parse_accounting("(100.0000) EOS"), Ok(Asset::from("-100.0000 EOS")));
+        assert_eq!(Asset::parse_accounting("100.0000 EOS"), Ok(Asset::from("100.0000 EOS")));
+        assert_eq!(Asset::parse_accounting("-100.0000 EOS"), Ok(Asset::from("-100.0000 EOS")));
+        assert_eq!(Asset::parse_accounting("(0.0001) EOS"), Ok(Asset::from("-0.0001 EOS")));
+    }
+
+    #[test]
+    fn test_parse_accounting_failed() {
+        let bad_amount = |input: &str, position| {
+            Err(ParseError::BadAmount {
+                input: input.to_string(),
+                position,
+            })
+        };
+        assert_eq!(Asset::parse_accounting("(-1.0000) EOS"), bad_amount("(-1.0000)", Some(1)));
+        assert_eq!(Asset::parse_accounting("(1.0000 EOS"), bad_amount("(1.0000", Some(0)));
+        assert_eq!(Asset::parse_accounting("1.0000) EOS"), bad_amount("1.0000)", Some(6)));
+        assert_eq!(Asset::parse_accounting("(1.00x0) EOS"), bad_amount("(1.00x0)", Some(5)));
+        assert_eq!(Asset::parse_accounting("() EOS"), bad_amount("()", None));
+        assert_eq!(
+            Asset::parse_accounting("(1.0000) eos"),
+            Err(ParseError::BadSymbolCode("eos".to_string()))
+        );
+        assert_eq!(Asset::parse_accounting("(1.0000)"), Err(ParseError::BadFormat));
+    }
+
+    #[test]
+    fn test_parse_grouped_failed() {
+        let bad_amount = |input: &str, position| {
+            Err(ParseError::BadAmount {
+                input: input.to_string(),
+                position,
+            })
+        };
+        assert_eq!(Asset::parse_grouped("1,234.000,0 EOS"), bad_amount("1,234.000,0", Some(9)));
+        assert_eq!(Asset::parse_grouped("1234.0,000 EOS"), bad_amount("1234.0,000", Some(6)));
+        assert_eq!(Asset::parse_grouped("1,23,456 EOS"), bad_amount("1,23,456", None));
+        assert_eq!(Asset::parse_grouped("1234,567 EOS"), bad_amount("1234,567", None));
+        assert_eq!(Asset::parse_grouped(",123 EOS"), bad_amount(",123", None));
+        assert_eq!(Asset::parse_grouped("1,234, EOS"), bad_amount("1,234,", None));
+        assert_eq!(Asset::parse_grouped("1,234.5x EOS"), bad_amount("1,234.5x", Some(7)));
+        assert_eq!(Asset::parse_grouped("1,234 eos"), Err(ParseError::BadSymbolCode("eos".to_string())));
+        assert_eq!(Asset::parse_grouped("1,234"), Err(ParseError::BadFormat));
+    }
+
+    #[test]
+    fn test_display() {
+        println!("{}", Asset::from_amount(10000, Symbol::from("4,SYM")))
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            Asset::from_amount(10000, Symbol::from("4,SYM")),
+            "1.0000 SYM".parse::<Asset>().unwrap()
+        );
+        assert_eq!(Asset::from_amount(100, Symbol::from("0,SYM")), "100 SYM".parse::<Asset>().unwrap());
+        assert_eq!(
+            Asset::from_amount(12345, Symbol::from("2,SYM")),
+            "123.45 SYM".parse::<Asset>().unwrap()
+        );
+        assert_eq!(
+            Asset::from_amount(-1000001, Symbol::from("4,SYM")),
+            "-100.0001 SYM".parse::<Asset>().unwrap()
+        );
+        assert_eq!(Asset::from_amount(0, Symbol::from("0,SYM")), "0 SYM".parse::<Asset>().unwrap());
+        assert_eq!(Asset::from_amount(0, Symbol::from("4,SYM")), "0.0000 SYM".parse::<Asset>().unwrap());
+        assert_eq!(Asset::from_amount(1, Symbol::from("4,SYM")), "0.0001 SYM".parse::<Asset>().unwrap());
+        assert_eq!(
+            Asset::from_amount(-1000000000000000000, Symbol::from("18,SYMBOLL")),
+            "-1.000000000000000000 SYMBOLL".parse::<Asset>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_str_unicode_space() {
+        let expected = Asset::from("1.0000 EOS");
+        assert_eq!("1.0000\u{00A0}EOS".parse::<Asset>(), Ok(expected));
+        assert_eq!("1.0000\u{202F}EOS".parse::<Asset>(), Ok(expected));
+        assert_eq!("1.0000\u{3000}EOS".parse::<Asset>(), Ok(expected));
+        assert_eq!("1.0000\tEOS".parse::<Asset>(), Err(ParseError::BadFormat));
+        assert_eq!("1.0000\u{00A0}EOS\u{00A0}FOO".parse::<Asset>(), Err(ParseError::BadFormat));
+        assert_eq!("1.0000\u{00A0} EOS".parse::<Asset>(), Err(ParseError::BadFormat));
+    }
+
+    #[test]
+    fn test_from_str_failed() {
+        assert_eq!("".parse::<Asset>(), Err(ParseError::BadFormat));
+        assert_eq!("-".parse::<Asset>(), Err(ParseError::BadFormat));
+        assert_eq!(
+            "- EOS".parse::<Asset>(),
+            Err(ParseError::BadAmount {
+                input: "-".to_string(),
+                position: None
+            })
+        );
+        assert_eq!(
+            "1s EOS".parse::<Asset>(),
+            Err(ParseError::BadAmount {
+                input: "1s".to_string(),
+                position: Some(1)
+            })
+        );
+        assert_eq!("1\nEOS".parse::<Asset>(), Err(ParseError::BadFormat));
+        assert_eq!("- 100 EOS".parse::<Asset>(), Err(ParseError::BadFormat));
+        assert_eq!("-".parse::<Asset>(), Err(ParseError::BadFormat));
+        assert_eq!("1.0000".parse::<Asset>(), Err(ParseError::BadFormat));
+        assert_eq!("10000".parse::<Asset>(), Err(ParseError::BadFormat));
+        assert_eq!(
+            "10000 LONGSYMBOL".parse::<Asset>(),
+            Err(ParseError::BadSymbolCode("LONGSYMBOL".to_string()))
+        );
+        assert_eq!(
+            "-0.0000000000000000000000000000000000000000000000000004371526177016610288 \\u0005".parse::<Asset>(),
+            Err(ParseError::BadSymbolCode("\\u0005".to_string())),
+        );
+    }
+
+    #[test]
+    fn test_from_str_bad_amount_position() {
+        let err = "12345.67x9 EOS".parse::<Asset>().unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::BadAmount {
+                input: "12345.67x9".to_string(),
+                position: Some(8)
+            }
+        );
+        assert_eq!(err.to_string(), "bad amount: 12345.67x9 (at position 8)");
+
+        let err = "1.0-0 EOS".parse::<Asset>().unwrap_err();
+        assert!(matches!(err, ParseError::BadAmount { position: Some(3), .. }));
+        let err = "é1 EOS".parse::<Asset>().unwrap_err();
+        assert!(matches!(err, ParseError::BadAmount { position: Some(0), .. }));
+
+        // well-formed but out of the i64 range, so there is no single offending character
+        let err = "99999999999999999999 EOS".parse::<Asset>().unwrap_err();
+        assert!(matches!(err, ParseError::BadAmount { position: None, .. }));
+        assert_eq!(err.to_string(), "bad amount: 99999999999999999999");
+    }
+
+    #[test]
+    fn test_from_str_empty_symbol() {
+        assert_eq!("1.0000 ".parse::<Asset>(), Err(ParseError::BadSymbolCode("".to_string())));
+        assert_eq!("1 ".parse::<Asset>(), Err(ParseError::BadSymbolCode("".to_string())));
+        assert_eq!("1.0000  EOS".parse::<Asset>(), Err(ParseError::BadFormat));
+        assert_eq!("1.0000 EOS ".parse::<Asset>(), Err(ParseError::BadFormat));
+        assert_eq!(" 1.0000 EOS".parse::<Asset>(), Err(ParseError::BadFormat));
+    }
+
+    #[test]
+    fn test_clamp() {
+        let min = Asset::from("1.0000 SYM");
+        let max = Asset::from("10.0000 SYM");
+        assert_eq!(Asset::from("5.0000 SYM").clamp(min, max), Asset::from("5.0000 SYM"));
+        assert_eq!(Asset::from("0.5000 SYM").clamp(min, max), min);
+        assert_eq!(Asset::from("-3.0000 SYM").clamp(min, max), min);
+        assert_eq!(Asset::from("12.0000 SYM").clamp(min, max), max);
+        assert_eq!(Asset::from("10.0000 SYM").clamp(min, max), max);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to clamp asset with different symbol")]
+    fn test_clamp_different_symbols() {
+        let _ = Asset::from("5.0000 SYM").clamp(Asset::from("1.000 SYM"), Asset::from("10.0000 SYM"));
+    }
+
+    #[test]
+    #[should_panic(expected = "clamp lower bound is greater than upper bound")]
+    fn test_clamp_inverted_range() {
+        let _ = Asset::from("5.0000 SYM").clamp(Asset::from("10.0000 SYM"), Asset::from("1.0000 SYM"));
+    }
+
+    #[test]
+    fn test_parse_scientific() {
+        assert_eq!(
+            Asset::parse_scientific("1e4 SYM", 4),
+            Ok(Asset::from_amount(100000000, Symbol::from("4,SYM")))
+        );
+        assert_eq!(
+            Asset::parse_scientific("1e3 EOS", 4),
+            Ok(Asset::from_amount(10000000, Symbol::from("4,EOS")))
+        );
+        assert_eq!(
+            Asset::parse_scientific("1.5E2 SYM", 0),
+            Ok(Asset::from_amount(150, Symbol::from("0,SYM")))
+        );
+        assert_eq!(
+            Asset::parse_scientific("-2.5e-3 SYM", 4),
+            Ok(Asset::from_amount(-25, Symbol::from("4,SYM")))
+        );
+        assert_eq!(
+            Asset::parse_scientific("1.2345 SYM", 6),
+            Ok(Asset::from_amount(1234500, Symbol::from("6,SYM")))
+        );
+        assert_eq!(
+            Asset::parse_scientific("0e-100 SYM", 4),
+            Ok(Asset::from_amount(0, Symbol::from("4,SYM")))
+        );
+    }
+
+    #[test]
+    fn test_parse_scientific_failed() {
+        assert_eq!(
+            Asset::parse_scientific("1.23456e1 SYM", 2),
+            Err(ParseError::BadAmount {
+                input: "1.23456e1".to_string(),
+                position: None
+            })
+        );
+        assert_eq!(
+            Asset::parse_scientific("1.2.3 SYM", 4),
+            Err(ParseError::BadAmount {
+                input: "1.2.3".to_string(),
+                position: None
+            })
+        );
+        assert_eq!(
+            Asset::parse_scientific("1..5e1 SYM", 2),
+            Err(ParseError::BadAmount {
+                input: "1..5e1".to_string(),
+                position: None
+            })
+        );
+        assert_eq!(Asset::parse_scientific("1e19 SYM", 4), Err(ParseError::OutOfRange));
+        assert_eq!(
+            Asset::parse_scientific("1 SYM", 19),
+            Err(ParseError::BadPrecision("19".to_string()))
+        );
+        assert_eq!(Asset::parse_scientific("-1e19 SYM", 4), Err(ParseError::OutOfRange));
+        assert_eq!(Asset::parse_scientific("1e100 SYM", 4), Err(ParseError::OutOfRange));
+        assert_eq!(Asset::parse_scientific("99999999999999999999 SYM", 0), Err(ParseError::OutOfRange));
+        assert_eq!(
+            Asset::parse_scientific("1e-100 SYM", 4),
+            Err(ParseError::BadAmount {
+                input: "1e-100".to_string(),
+                position: None
+            })
+        );
+        assert_eq!(
+            Asset::parse_scientific("1e SYM", 4),
+            Err(ParseError::BadAmount {
+                input: "1e".to_string(),
+                position: None
+            })
+        );
+        assert_eq!(
+            Asset::parse_scientific("e3 SYM", 4),
+            Err(ParseError::BadAmount {
+                input: "e3".to_string(),
+                position: None
+            })
+        );
+        assert_eq!(
+            Asset::parse_scientific("1e3 sym", 4),
+            Err(ParseError::BadSymbolCode("sym".to_string()))
+        );
+        assert_eq!(Asset::parse_scientific("1e3", 4), Err(ParseError::BadFormat));
+    }
+
+    #[test]
+    fn test_value() {
+        let sym = Symbol::from("4,SYM");
+        assert_eq!(Asset::from_amount(15000, sym).value(), 1.5);
+    }
+
+    #[test]
+    #[cfg(feature = "crypto")]
+    fn test_digest() {
+        let asset = Asset::from("1.0000 EOS");
+        assert_eq!(asset.digest(), Asset::from("1.0000 EOS").digest());
+        assert_eq!(asset.digest(), Checksum256::hash(&asset.to_packed()));
+        assert_ne!(asset.digest(), Asset::from("1.0001 EOS").digest());
+        assert_ne!(asset.digest(), Asset::from("1.000 EOS").digest());
+        assert_ne!(asset.digest(), Asset::from("1.0000 WAX").digest());
+    }
+
+    #[test]
+    fn test_minimal_units() {
+        let asset = Asset::from("1.0000 EOS");
+        assert_eq!(asset.to_minimal_units(), 10000);
+        assert_eq!(Asset::from_minimal_units(asset.to_minimal_units(), asset.symbol), asset);
+        assert_eq!(Asset::from_minimal_units(-1, Symbol::from("2,USD")).to_string(), "-0.01 USD");
+        let max = Asset::from_minimal_units(Asset::MAX_AMOUNT, Symbol::from("4,EOS"));
+        assert_eq!(max.to_minimal_units(), Asset::MAX_AMOUNT);
+    }
+
+    #[test]
+    #[should_panic(expected = "magnitude of asset amount must be less than 2^62")]
+    fn test_from_minimal_units_out_of_range() {
+        let _ = Asset::from_minimal_units(i64::MIN, Symbol::from("4,EOS"));
+    }
+
+    #[test]
+    fn test_to_csv_fields() {
+        assert_eq!(Asset::from("123.45 SYM").to_csv_fields(), (12345, 2, "SYM".to_string()));
+        assert_eq!(Asset::from("-1 EOS").to_csv_fields(), (-1, 0, "EOS".to_string()));
+        let (amount, precision, code) = Asset::from("0.0001 EOS").to_csv_fields();
+        assert_eq!(Asset::try_from_parts(amount, precision, &code), Ok(Asset::from("0.0001 EOS")));
+    }
+
+    #[test]
+    fn test_values() {
+        let assets = [Asset::from("1.5000 EOS"), Asset::from("-0.2500 EOS"), Asset::from("10.0000 EOS")];
+        assert_eq!(Asset::values(&assets), [1.5, -0.25, 10.0]);
+        assert_eq!(Asset::total_value_same_symbol(&assets), Some(11.25));
+        assert_eq!(Asset::values(&[]), Vec::<f64>::new());
+        assert_eq!(Asset::total_value_same_symbol(&[]), Some(0.0));
+
+        let mixed = [Asset::from("1.0000 EOS"), Asset::from("1.000 EOS")];
+        assert_eq!(Asset::total_value_same_symbol(&mixed), None);
+    }
+
+    #[test]
+    fn test_min_max_by_value() {
+        let assets = [
+            Asset::from("1.0000 EOS"),
+            Asset::from("25.00 USD"),
+            Asset::from("-3 SYS"),
+            Asset::from("2.50000000 BTC"),
+        ];
+        assert_eq!(Asset::max_by_value(&assets).unwrap().to_string(), "25.00 USD");
+        assert_eq!(Asset::min_by_value(&assets).unwrap().to_string(), "-3 SYS");
+        assert!(Asset::max_by_value(&[]).is_none());
+        assert!(Asset::min_by_value(&[]).is_none());
+    }
 
     #[test]
-    fn test_cdt_1() {
-        assert_eq!(Asset::new().symbol.raw(), 0);
-        assert_eq!(Asset::new().amount, 0);
+    fn test_denominations() {
+        let denominations: Vec<String> = Asset::denominations(Symbol::from("4,EOS")).take(5).map(|a| a.to_string()).collect();
+        assert_eq!(
+            denominations,
+            ["0.0001 EOS", "0.0010 EOS", "0.0100 EOS", "0.1000 EOS", "1.0000 EOS"]
+        );
+
+        let last = Asset::denominations(Symbol::from("0,SYS")).last().unwrap();
+        assert_eq!(last.amount, 1_000_000_000_000_000_000);
+        assert_eq!(Asset::denominations(Symbol::from("0,SYS")).count(), 19);
     }
 
     #[test]
-    fn test_asset_creation() {
-        let asset = Asset {
-            amount: 1000,
-            symbol: Symbol::from("4,SYS"),
-        };
-        assert_eq!(asset.amount, 1000);
-        assert_eq!(asset.symbol, Symbol::from("4,SYS"));
+    fn test_integer_sqrt_product() {
+        let a = Asset::from("4.0000 EOS");
+        let b = Asset::from("9.0000 USDT");
+        assert_eq!(Asset::integer_sqrt_product(&a, &b), Some(60_000));
+        assert_eq!(Asset::integer_sqrt_product(&Asset::from("2 A"), &Asset::from("3 B")), Some(2));
+        assert_eq!(Asset::integer_sqrt_product(&Asset::from("0 A"), &Asset::from("3 B")), Some(0));
+        let max = Asset::from_amount(Asset::MAX_AMOUNT, Symbol::from("0,A"));
+        assert_eq!(Asset::integer_sqrt_product(&max, &max), Some(Asset::MAX_AMOUNT as i128));
+        assert_eq!(Asset::integer_sqrt_product(&Asset::from("-1 A"), &Asset::from("-1 B")), None);
+        assert_eq!(Asset::integer_sqrt_product(&Asset::from("1 A"), &Asset::from("-1 B")), None);
     }
 
     #[test]
-    fn test_asset_equality() {
-        let asset1 = Asset {
-            amount: 1000,
-            symbol: Symbol::from("4,SYS"),
-        };
-        let asset2 = Asset {
-            amount: 1000,
-            symbol: Symbol::from("4,SYS"),
-        };
-        assert_eq!(asset1, asset2);
+    fn test_assign_checked() {
+        let mut total = Asset::from("1.0000 EOS");
+        assert_eq!(total.add_assign_checked(Asset::from("2.5000 EOS")), Ok(()));
+        assert_eq!(total, Asset::from("3.5000 EOS"));
+        assert_eq!(total.sub_assign_checked(Asset::from("4.0000 EOS")), Ok(()));
+        assert_eq!(total, Asset::from("-0.5000 EOS"));
     }
 
     #[test]
-    #[should_panic(expected = "comparison of assets with different symbols is not allowed")]
-    fn test_equality_operator_panics() {
-        let asset1 = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
+    fn test_assign_checked_failed() {
+        let max = Asset::from_amount(Asset::MAX_AMOUNT, Symbol::from("4,EOS"));
+        let mut total = max;
+        assert_eq!(total.add_assign_checked(Asset::from("0.0001 EOS")), Err(ParseError::OutOfRange));
+        assert_eq!(total, max);
+        let mut total = -max;
+        assert_eq!(total.sub_assign_checked(Asset::from("0.0001 EOS")), Err(ParseError::OutOfRange));
+        assert_eq!(total, -max);
+        let mut total = Asset::from("1.0000 EOS");
+        assert_eq!(
+            total.add_assign_checked(Asset::from("1.00 EOS")),
+            Err(ParseError::SymbolMismatch {
+                expected: Symbol::from("4,EOS"),
+                found: Symbol::from("2,EOS")
+            })
+        );
+        assert_eq!(total, Asset::from("1.0000 EOS"));
+    }
 
-        let asset2 = Asset {
-            amount: 100,
-            symbol: Symbol::from("5,SYM"),
-        };
+    #[test]
+    fn test_from_decimal_str() {
+        let eos = Symbol::from("4,EOS");
+        assert_eq!(
+            Asset::from_decimal_str("1.23456", eos, Rounding::HalfUp),
+            Ok(Asset::from("1.2346 EOS"))
+        );
+        assert_eq!(
+            Asset::from_decimal_str("1.23456", eos, Rounding::TowardZero),
+            Ok(Asset::from("1.2345 EOS"))
+        );
+        assert_eq!(
+            Asset::from_decimal_str("-1.23456", eos, Rounding::HalfUp),
+            Ok(Asset::from("-1.2346 EOS"))
+        );
+        assert_eq!(
+            Asset::from_decimal_str("1.23455", eos, Rounding::HalfEven),
+            Ok(Asset::from("1.2346 EOS"))
+        );
+        assert_eq!(
+            Asset::from_decimal_str("1.23445", eos, Rounding::HalfEven),
+            Ok(Asset::from("1.2344 EOS"))
+        );
+        assert_eq!(Asset::from_decimal_str("+2", eos, Rounding::HalfUp), Ok(Asset::from("2.0000 EOS")));
+        assert_eq!(Asset::from_decimal_str(".5", eos, Rounding::HalfUp), Ok(Asset::from("0.5000 EOS")));
+        assert_eq!(
+            Asset::from_decimal_str("0.5", Symbol::from("0,SYS"), Rounding::HalfUp),
+            Ok(Asset::from("1 SYS"))
+        );
 
-        let _ = asset1 == asset2;
+        // a long tail still decides ties
+        let tie = format!("0.00005{}", "0".repeat(40));
+        assert_eq!(
+            Asset::from_decimal_str(&tie, eos, Rounding::HalfEven),
+            Ok(Asset::from("0.0000 EOS"))
+        );
+        let above = format!("0.00005{}1", "0".repeat(40));
+        assert_eq!(
+            Asset::from_decimal_str(&above, eos, Rounding::HalfEven),
+            Ok(Asset::from("0.0001 EOS"))
+        );
     }
 
     #[test]
-    fn test_inequality_operator() {
-        let asset1 = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
+    fn test_from_decimal_str_failed() {
+        let eos = Symbol::from("4,EOS");
+        assert!(matches!(
+            Asset::from_decimal_str("1.2.3", eos, Rounding::HalfUp),
+            Err(ParseError::BadAmount { .. })
+        ));
+        assert_eq!(
+            Asset::from_decimal_str("1a", eos, Rounding::HalfUp),
+            Err(ParseError::BadAmount {
+                input: "1a".to_string(),
+                position: Some(1)
+            })
+        );
+        assert!(Asset::from_decimal_str("", eos, Rounding::HalfUp).is_err());
+        assert!(Asset::from_decimal_str("-", eos, Rounding::HalfUp).is_err());
+        assert!(Asset::from_decimal_str("1.0 EOS", eos, Rounding::HalfUp).is_err());
+        assert_eq!(
+            Asset::from_decimal_str("461168601842738.79029", eos, Rounding::HalfUp),
+            Ok(Asset::from_amount(Asset::MAX_AMOUNT, eos))
+        );
+        assert_eq!(
+            Asset::from_decimal_str("461168601842738.79035", eos, Rounding::HalfUp),
+            Err(ParseError::OutOfRange)
+        );
+        assert_eq!(
+            Asset::from_decimal_str("99999999999999999999999999999999999999999", eos, Rounding::HalfUp),
+            Err(ParseError::OutOfRange)
+        );
+    }
 
-        let asset2 = Asset {
-            amount: 200,
-            symbol: Symbol::from("4,SYM"),
-        };
+    #[test]
+    fn test_from_str_trailing_dot() {
+        assert_eq!(
+            "1. EOS".parse::<Asset>(),
+            Err(ParseError::BadAmount {
+                input: "1.".to_string(),
+                position: Some(1)
+            })
+        );
+        assert!("-0. EOS".parse::<Asset>().is_err());
+        assert!(". EOS".parse::<Asset>().is_err());
+        assert_eq!("1 EOS".parse::<Asset>(), Ok(Asset::from_amount(1, Symbol::from("0,EOS"))));
+    }
 
-        assert_ne!(asset1, asset2);
+    #[test]
+    fn test_roundtrip_range_boundaries() {
+        for s in [
+            "4611686018427387903 MAX",
+            "-4611686018427387903 MIN",
+            "46116860184.27387903 WAX",
+            "0.461168601842738790 ZZZZZZZ",
+            "0.000000000000000001 A",
+        ] {
+            let asset = s.parse::<Asset>().unwrap();
+            assert_eq!(asset.to_string(), s);
+            assert!(asset.is_amount_within_range());
+        }
     }
 
     #[test]
-    #[should_panic(expected = "comparison of assets with different symbols is not allowed")]
-    fn test_inequality_operator_panics() {
-        let asset1 = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
+    fn test_from_str_max_precision() {
+        let max = "0.000000000000000001 SYM";
+        let asset = max.parse::<Asset>().unwrap();
+        assert_eq!(asset.symbol.precision(), Symbol::MAX_PRECISION);
+        assert_eq!(asset.to_string(), max);
 
-        let asset2 = Asset {
-            amount: 100,
-            symbol: Symbol::from("5,SYM"),
-        };
+        assert_eq!(
+            "0.0000000000000000001 SYM".parse::<Asset>(),
+            Err(ParseError::BadPrecision("19".to_string()))
+        );
+        assert_eq!(
+            "0.000000000000000000000000000000000000000000000000000000010000000000001 JIAYOUY".parse::<Asset>(),
+            Err(ParseError::BadPrecision("69".to_string()))
+        );
+        // 256 digits used to wrap around to precision 0
+        let over = format!("0.{}1 SYM", "0".repeat(255));
+        assert_eq!(over.parse::<Asset>(), Err(ParseError::BadPrecision("256".to_string())));
+    }
 
-        let _ = asset1 != asset2;
+    #[test]
+    fn test_from_str_leading_dot() {
+        let asset = ".5 EOS".parse::<Asset>().unwrap();
+        assert_eq!((asset.amount, asset.symbol), (5, Symbol::from("1,EOS")));
+        assert_eq!(asset.to_string(), "0.5 EOS");
+        let asset = "-.5 EOS".parse::<Asset>().unwrap();
+        assert_eq!((asset.amount, asset.symbol), (-5, Symbol::from("1,EOS")));
+        assert_eq!(asset.to_string(), "-0.5 EOS");
+        assert_eq!("-.0001 EOS".parse::<Asset>().unwrap().to_string(), "-0.0001 EOS");
     }
 
     #[test]
-    fn test_ord_operator() {
-        let asset1 = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
+    fn test_display_roundtrip_all_precisions() {
+        for precision in 0..=18 {
+            let symbol = Symbol::from_precision(SymbolCode::from("SYM"), precision);
+            for amount in [0, 1, -1, 12345, Asset::MAX_AMOUNT, Asset::MIN_AMOUNT] {
+                let asset = Asset::from_amount(amount, symbol);
+                let parsed = asset.to_string().parse::<Asset>();
+                assert_eq!(parsed, Ok(asset), "{} at precision {}", amount, precision);
+                assert_eq!(parsed.unwrap().symbol, symbol);
+            }
+        }
+    }
 
-        let asset2 = Asset {
-            amount: 200,
-            symbol: Symbol::from("4,SYM"),
-        };
+    #[test]
+    fn test_to_string_code_lengths() {
+        for len in 1..=SymbolCode::MAX_LENGTH {
+            let code = &"ABCDEFG"[..len];
+            let asset = Asset::from_amount(-12345, Symbol::from_precision(SymbolCode::from(code), 2));
+            assert_eq!(asset.to_string(), format!("-123.45 {}", code));
+            assert_eq!(asset.to_string().parse::<Asset>(), Ok(asset));
+        }
+        let asset = Asset::from_amount(1, Symbol::from("0,ZZZZZZZ"));
+        assert_eq!(asset.to_string(), "1 ZZZZZZZ");
+    }
 
-        let asset3 = Asset {
-            amount: 200,
-            symbol: Symbol::from("4,SYM"),
-        };
+    #[test]
+    fn test_format_currency() {
+        let mut fmt = CurrencyFormat::new();
+        fmt.register(SymbolCode::from("USD"), "$", "");
+        fmt.register(SymbolCode::from("BTC"), "", " ₿");
+        assert_eq!(Asset::from("12.34 USD").format_currency(&fmt), "$12.34");
+        assert_eq!(Asset::from("-0.05 USD").format_currency(&fmt), "-$0.05");
+        assert_eq!(Asset::from("1.00000000 BTC").format_currency(&fmt), "1.00000000 ₿");
+        assert_eq!(Asset::from("1.0000 EOS").format_currency(&fmt), "1.0000 EOS");
+        assert_eq!(Asset::from("12.34 USD").format_currency(&CurrencyFormat::new()), "12.34 USD");
+    }
 
-        assert!(asset1 < asset2);
-        assert!(asset2 > asset1);
-        assert!(asset1 <= asset2);
-        assert!(asset2 >= asset1);
-        assert!(asset3 >= asset2);
-        assert!(asset3 <= asset2);
+    #[test]
+    fn test_to_string_signed() {
+        assert_eq!(Asset::from("1.0000 EOS").to_string_signed(), "+1.0000 EOS");
+        assert_eq!(Asset::from("-0.0001 EOS").to_string_signed(), "-0.0001 EOS");
+        assert_eq!(Asset::from("0.0000 EOS").to_string_signed(), "0.0000 EOS");
+        assert_eq!(Asset::from("5 SYS").to_string_signed(), "+5 SYS");
+        assert_eq!(Asset::from("1.0000 EOS").to_string(), "1.0000 EOS");
     }
 
     #[test]
-    #[should_panic(expected = "comparison of assets with different symbols is not allowed")]
-    fn test_ord_operator_panics() {
-        let asset1 = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
+    fn test_cmp_checked() {
+        let a = Asset::from("1.0000 EOS");
+        assert_eq!(a.cmp_checked(&Asset::from("2.0000 EOS")), Ok(std::cmp::Ordering::Less));
+        assert_eq!(a.cmp_checked(&a), Ok(std::cmp::Ordering::Equal));
+        assert!(matches!(
+            a.cmp_checked(&Asset::from("1.000 EOS")),
+            Err(ParseError::SymbolMismatch { .. })
+        ));
+    }
 
-        let asset2 = Asset {
-            amount: 100,
-            symbol: Symbol::from("5,SYM"),
-        };
+    #[test]
+    fn test_mul_i128() {
+        let big = i64::MAX as i128 * 4;
+        assert_eq!(Asset::from("0.0000 EOS").mul_i128(big), Asset::from("0.0000 EOS"));
+        assert_eq!(Asset::from("0.0001 EOS").mul_i128(10_000), Asset::from("1.0000 EOS"));
+        assert_eq!(
+            Asset::from("-0.0001 EOS").mul_i128(-(Asset::MAX_AMOUNT as i128)).amount,
+            Asset::MAX_AMOUNT
+        );
+        assert_eq!(Asset::from("3 SYS").mul_i128(-2), Asset::from("-6 SYS"));
+    }
 
-        let _ = asset1 > asset2;
+    #[test]
+    #[should_panic(expected = "multiplication overflow")]
+    fn test_mul_i128_overflow() {
+        let _ = Asset::from("0.0001 EOS").mul_i128(i64::MAX as i128 * 4);
     }
 
     #[test]
-    fn test_neg() {
-        let asset = Asset::from_amount(100, Symbol::new());
-        let negated_asset = -asset;
-        assert_eq!(negated_asset.amount, -100);
+    #[should_panic(expected = "multiplication overflow")]
+    fn test_mul_i128_overflow_128() {
+        let _ = Asset::from("-2 SYS").mul_i128(i128::MIN);
     }
 
     #[test]
-    fn test_sub_assign() {
-        let mut asset1 = Asset {
-            amount: 100,
-            symbol: Symbol::new(),
-        };
-        let asset2 = Asset {
-            amount: 50,
-            symbol: Symbol::new(),
-        };
+    #[should_panic(expected = "multiplication underflow")]
+    fn test_mul_i128_underflow() {
+        let _ = Asset::from("2 SYS").mul_i128(i128::MIN);
+    }
 
-        asset1 -= asset2;
+    #[test]
+    fn test_mul_div_checked() {
+        let a = Asset::from("1.5000 EOS");
+        assert_eq!(a.mul_checked(-3), Ok(Asset::from("-4.5000 EOS")));
+        assert_eq!(a.div_checked(4), Ok(Asset::from("0.3750 EOS")));
+        assert_eq!(Asset::from("0.0001 EOS").div_checked(2), Ok(Asset::from("0.0000 EOS")));
+
+        let max = Asset::from_amount(Asset::MAX_AMOUNT, Symbol::from("4,EOS"));
+        assert_eq!(max.mul_checked(2), Err(ParseError::OutOfRange));
+        assert_eq!(max.mul_checked(-2), Err(ParseError::OutOfRange));
+        assert_eq!(a.div_checked(0), Err(ParseError::DivisionByZero));
+        assert_eq!(
+            Asset::from_amount(i64::MIN, Symbol::from("4,EOS")).div_checked(-1),
+            Err(ParseError::OutOfRange)
+        );
+    }
 
-        assert_eq!(asset1.amount, 50);
+    #[test]
+    fn test_div_checked_by_zero() {
+        let err = Asset::from("1.0000 EOS").div_checked(0).unwrap_err();
+        assert_eq!(err, ParseError::DivisionByZero);
+        assert_eq!(err.to_string(), "division by zero");
+        assert_eq!(Asset::new().div_checked(0), Err(ParseError::DivisionByZero));
     }
 
     #[test]
-    #[should_panic(expected = "attempt to subtract asset with different symbol")]
-    fn test_sub_assign_with_different_symcode() {
-        let mut asset1 = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
-        let asset2 = Asset {
-            amount: 50,
-            symbol: Symbol::from("4,TST"),
-        };
+    fn test_from_str_checked() {
+        let eos = Symbol::from("4,EOS");
+        assert_eq!(Asset::from_str_checked("1.0000 EOS", eos), Ok(Asset::from("1.0000 EOS")));
+        let err = Asset::from_str_checked("1.00 EOS", eos).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::SymbolMismatch {
+                expected: eos,
+                found: Symbol::from("2,EOS")
+            }
+        );
+        assert_eq!(err.to_string(), "symbol mismatch: expected 4,EOS, found 2,EOS");
+        assert!(matches!(
+            Asset::from_str_checked("1.0000 USD", eos),
+            Err(ParseError::SymbolMismatch { .. })
+        ));
+        assert_eq!(Asset::from_str_checked("1.0000", eos), Err(ParseError::BadFormat));
+    }
 
-        asset1 -= asset2;
+    #[test]
+    fn test_parse_symbol_first() {
+        assert_eq!(Asset::parse_symbol_first("EOS 1.0000"), Ok(Asset::from("1.0000 EOS")));
+        assert_eq!(Asset::parse_symbol_first(" USD -2.50 "), Ok(Asset::from("-2.50 USD")));
+        assert_eq!(Asset::parse_symbol_first("SYS 100"), Ok(Asset::from("100 SYS")));
     }
 
     #[test]
-    #[should_panic(expected = "attempt to subtract asset with different symbol")]
-    fn test_sub_assign_with_different_precision() {
-        let mut asset1 = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
-        let asset2 = Asset {
-            amount: 50,
-            symbol: Symbol::from("5,SYM"),
-        };
+    fn test_parse_symbol_first_failed() {
+        assert_eq!(
+            Asset::parse_symbol_first("1.0000 EOS"),
+            Err(ParseError::BadSymbolCode("1.0000".to_string()))
+        );
+        assert!(matches!(Asset::parse_symbol_first("EOS USD"), Err(ParseError::BadAmount { .. })));
+        assert_eq!(Asset::parse_symbol_first("EOS 1.0 USD"), Err(ParseError::BadFormat));
+        assert_eq!(Asset::parse_symbol_first("EOS"), Err(ParseError::BadFormat));
+        assert_eq!(Asset::parse_symbol_first(""), Err(ParseError::BadFormat));
+        assert_eq!(
+            Asset::parse_symbol_first("eos 1.0"),
+            Err(ParseError::BadSymbolCode("eos".to_string()))
+        );
+    }
 
-        asset1 -= asset2;
+    #[test]
+    #[cfg(feature = "serde_json")]
+    fn test_to_json_value() {
+        let value = Asset::from("1.0000 EOS").to_json_value();
+        assert_eq!(value, serde_json::Value::String("1.0000 EOS".to_string()));
+        assert_eq!(value.to_string(), r#""1.0000 EOS""#);
+        let args = serde_json::json!({ "quantity": Asset::from("-0.50 USD").to_json_value() });
+        assert_eq!(args.to_string(), r#"{"quantity":"-0.50 USD"}"#);
     }
 
     #[test]
-    #[should_panic(expected = "subtraction underflow")]
-    fn test_sub_assign_overflow() {
-        let mut asset1 = Asset {
-            amount: -Asset::MAX_AMOUNT,
-            symbol: Symbol::new(),
-        };
-        let asset2 = Asset {
-            amount: 1,
-            symbol: Symbol::new(),
-        };
+    fn test_e18() {
+        let asset = Asset::from("1.2345 SYM");
+        assert_eq!(asset.to_e18(), 1_234_500_000_000_000_000);
+        assert_eq!(Asset::from_e18(asset.to_e18(), asset.symbol), Ok(asset));
+        assert_eq!(Asset::from("-0.000000000000000001 SYM").to_e18(), -1);
+        assert_eq!(Asset::from("5 SYM").to_e18(), 5_000_000_000_000_000_000);
 
-        asset1 -= asset2;
+        let sym = Symbol::from("4,SYM");
+        assert_eq!(Asset::from_e18(1_234_550_000_000_000_000, sym), Ok(Asset::from("1.2346 SYM")));
+        assert_eq!(Asset::from_e18(-1_234_549_999_999_999_999, sym), Ok(Asset::from("-1.2345 SYM")));
+        assert_eq!(Asset::from_e18(i128::MAX, sym), Err(ParseError::OutOfRange));
+
+        let high = Asset::from_amount(123, raw_symbol(20, "SYM"));
+        assert_eq!(high.to_e18(), 1);
+        assert_eq!(Asset::from_e18(1, high.symbol), Ok(Asset::from_amount(100, high.symbol)));
+        assert_eq!(Asset::from_amount(1, raw_symbol(60, "SYM")).to_e18(), 0);
+        assert_eq!(Asset::from_e18(1, raw_symbol(60, "SYM")), Err(ParseError::OutOfRange));
+        assert_eq!(
+            Asset::from_e18(0, raw_symbol(60, "SYM")),
+            Ok(Asset::from_amount(0, raw_symbol(60, "SYM")))
+        );
     }
 
     #[test]
-    #[should_panic(expected = "attempt to add asset with different symbol")]
-    fn test_add_assign_with_different_symcode() {
-        let mut asset1 = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
-        let asset2 = Asset {
-            amount: 50,
-            symbol: Symbol::from("4,TST"),
-        };
+    fn test_db_parts() {
+        let asset = Asset::from("1.0000 EOS");
+        assert_eq!(asset.into_db_parts(), (10000, "4,EOS".to_string()));
+        for s in ["1.0000 EOS", "-0.01 USD", "0 SYS", "123.45678901 BTC"] {
+            let (amount, symbol) = Asset::from(s).into_db_parts();
+            assert_eq!(Asset::from_db_parts(amount, &symbol), Ok(Asset::from(s)));
+        }
+    }
 
-        asset1 += asset2;
+    #[test]
+    fn test_from_db_parts_failed() {
+        assert_eq!(Asset::from_db_parts(1, "EOS"), Err(ParseError::BadFormat));
+        assert_eq!(Asset::from_db_parts(1, "4,eos"), Err(ParseError::BadSymbolCode("eos".to_string())));
+        assert_eq!(Asset::from_db_parts(i64::MAX, "4,EOS"), Err(ParseError::OutOfRange));
     }
 
     #[test]
-    #[should_panic(expected = "attempt to add asset with different symbol")]
-    fn test_add_assign_with_different_precision() {
-        let mut asset1 = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
-        let asset2 = Asset {
-            amount: 50,
-            symbol: Symbol::from("5,SYM"),
-        };
+    fn test_is_uninitialized() {
+        assert!(Asset::new().is_uninitialized());
+        assert!(Asset::default().is_uninitialized());
+        assert!(!Asset::from("0.0000 EOS").is_uninitialized());
+        assert!(!Asset::from_amount(1, Symbol::new()).is_uninitialized());
+    }
 
-        asset1 += asset2;
+    #[test]
+    fn test_uninitialized_comparisons() {
+        let uninitialized = Asset::new();
+        let eos = Asset::from("0.0000 EOS");
+        assert!(!uninitialized.nominal_eq(&eos));
+        assert!(!eos.nominal_eq(&uninitialized));
+        assert!(uninitialized.nominal_eq(&Asset::new()));
+        assert!(matches!(uninitialized.cmp_checked(&eos), Err(ParseError::SymbolMismatch { .. })));
+        assert_eq!(uninitialized.checked_add(eos), None);
+        assert_eq!(eos.checked_sub(uninitialized), None);
     }
 
     #[test]
-    #[should_panic(expected = "addition overflow")]
-    fn test_add_assign_overflow() {
-        let mut asset1 = Asset {
-            amount: Asset::MAX_AMOUNT,
-            symbol: Symbol::new(),
-        };
-        let asset2 = Asset {
-            amount: 1,
-            symbol: Symbol::new(),
-        };
+    fn test_add_rescaled() {
+        let sum = Asset::from("1.0 SYM").add_rescaled(Asset::from("1.00 SYM"));
+        assert_eq!(sum, Ok(Asset::from("2.00 SYM")));
+        let sum = Asset::from("1.2345 SYM").add_rescaled(Asset::from("-1 SYM"));
+        assert_eq!(sum, Ok(Asset::from("0.2345 SYM")));
+        let sum = Asset::from("1.00 SYM").add_rescaled(Asset::from("0.01 SYM"));
+        assert_eq!(sum, Ok(Asset::from("1.01 SYM")));
+    }
 
-        asset1 += asset2;
+    #[test]
+    fn test_add_rescaled_failed() {
+        assert_eq!(
+            Asset::from("1.0 SYM").add_rescaled(Asset::from("1.0 EOS")),
+            Err(ParseError::BadSymbol("1,EOS".to_string()))
+        );
+        let max = Asset::from_amount(Asset::MAX_AMOUNT, Symbol::from("0,SYM"));
+        assert_eq!(max.add_rescaled(Asset::from("0.1 SYM")), Err(ParseError::OutOfRange));
+        assert_eq!(max.add_rescaled(Asset::from("1 SYM")), Err(ParseError::OutOfRange));
+    }
+
+    #[test]
+    fn test_nominal_eq() {
+        assert!(Asset::from("1.0000 EOS").nominal_eq(&Asset::from("1.000 EOS")));
+        assert!(Asset::from("1.000 EOS").nominal_eq(&Asset::from("1.0000 EOS")));
+        assert!(Asset::from("-1.5 EOS").nominal_eq(&Asset::from("-1.50 EOS")));
+        assert!(Asset::from("0 EOS").nominal_eq(&Asset::from("0.0000 EOS")));
+        assert!(!Asset::from("1.0001 EOS").nominal_eq(&Asset::from("1.000 EOS")));
+        assert!(!Asset::from("1.0000 EOS").nominal_eq(&Asset::from("1.0000 WAX")));
+        let max = Asset::from_amount(Asset::MAX_AMOUNT, Symbol::from("0,EOS"));
+        assert!(!max.nominal_eq(&Asset::from_amount(Asset::MAX_AMOUNT, Symbol::from("4,EOS"))));
     }
 
     #[test]
-    fn test_asset_addition() {
-        let asset_a = Asset {
-            symbol: Symbol::from("4,SYS"),
-            amount: 1000,
-        };
-        let asset_b = Asset {
-            symbol: Symbol::from("4,SYS"),
-            amount: 2000,
-        };
+    fn test_value_approx_eq() {
+        let computed = Asset::from("1.2345 EOS");
+        assert!(computed.value_approx_eq(&Asset::from("1.23 USD"), 0.005));
+        assert!(computed.value_approx_eq(&Asset::from("1.234500 EOS"), 0.0));
+        assert!(computed.value_approx_eq(&Asset::from("1.2346 EOS"), 0.0001 + f64::EPSILON));
+        assert!(!computed.value_approx_eq(&Asset::from("1.23 USD"), 0.001));
+        assert!(!computed.value_approx_eq(&Asset::from("2.0000 EOS"), 0.5));
+        assert!(!computed.value_approx_eq(&Asset::from("-1.2345 EOS"), 1.0));
+        assert!(!computed.value_approx_eq(&computed, f64::NAN));
+    }
 
-        let result = asset_a + asset_b;
-        assert_eq!(result.symbol, Symbol::from("4,SYS"));
-        assert_eq!(result.amount, 3000);
+    #[test]
+    fn test_percentage_of() {
+        let supply = Asset::from("100.0000 EOS");
+        assert_eq!(Asset::from("5.0000 EOS").percentage_of(&supply), Some(5.0));
+        assert_eq!(Asset::from("250.0000 EOS").percentage_of(&supply), Some(250.0));
+        assert_eq!(Asset::from("-1.0000 EOS").percentage_of(&supply), Some(-1.0));
+        assert_eq!(Asset::from("5.0000 EOS").percentage_of(&Asset::from("0.0000 EOS")), None);
+        assert_eq!(Asset::from("5.0000 EOS").percentage_of(&Asset::from("100.000 EOS")), None);
+        assert_eq!(Asset::from("5.0000 FOO").percentage_of(&supply), None);
     }
 
     #[test]
-    fn test_asset_subtraction() {
-        let asset_a = Asset {
-            symbol: Symbol::from("4,SYS"),
-            amount: 3000,
-        };
-        let asset_b = Asset {
-            symbol: Symbol::from("4,SYS"),
-            amount: 2000,
-        };
+    fn test_amount_hex_le() {
+        let sym = Symbol::from("4,EOS");
+        let asset = Asset::from_amount(10000, sym);
+        assert_eq!(asset.amount_hex_le(), "1027000000000000");
+        assert_eq!(Asset::from_amount_hex_le("1027000000000000", sym), Ok(asset));
+        assert_eq!(
+            Asset::from_amount_hex_le("1027000000000000", sym).unwrap().to_string(),
+            "1.0000 EOS"
+        );
+        assert_eq!(Asset::from_amount(-1, sym).amount_hex_le(), "ffffffffffffffff");
+        assert_eq!(Asset::from_amount_hex_le("FFFFFFFFFFFFFFFF", sym).map(|a| a.amount), Ok(-1));
+    }
 
-        let result = asset_a - asset_b;
-        assert_eq!(result.symbol, Symbol::from("4,SYS"));
-        assert_eq!(result.amount, 1000);
+    #[test]
+    fn test_from_amount_hex_le_failed() {
+        let sym = Symbol::from("4,EOS");
+        assert_eq!(
+            Asset::from_amount_hex_le("", sym),
+            Err(ParseError::BadAmount {
+                input: "".to_string(),
+                position: None
+            })
+        );
+        assert_eq!(
+            Asset::from_amount_hex_le("10270000000000", sym),
+            Err(ParseError::BadAmount {
+                input: "10270000000000".to_string(),
+                position: None
+            })
+        );
+        assert_eq!(
+            Asset::from_amount_hex_le("1027000000000g00", sym),
+            Err(ParseError::BadAmount {
+                input: "1027000000000g00".to_string(),
+                position: None
+            })
+        );
+        assert_eq!(
+            Asset::from_amount_hex_le("+027000000000000", sym),
+            Err(ParseError::BadAmount {
+                input: "+027000000000000".to_string(),
+                position: None
+            })
+        );
+        assert_eq!(Asset::from_amount_hex_le("ffffffffffffff7f", sym), Err(ParseError::OutOfRange));
     }
 
     #[test]
-    fn test_mul_assign() {
-        let mut asset = Asset {
-            symbol: Symbol::from("4,SYS"),
-            amount: 10,
-        };
-        asset *= 2;
-        assert_eq!(asset.amount, 20);
-        asset *= 3;
-        assert_eq!(asset.amount, 60);
+    fn test_to_string_small_negative() {
+        assert_eq!(Asset::from_amount(-1, Symbol::from("4,SYM")).to_string(), "-0.0001 SYM");
+        assert_eq!(Asset::from_amount(-9999, Symbol::from("4,SYM")).to_string(), "-0.9999 SYM");
+        assert_eq!("-0.0001 SYM".parse::<Asset>().unwrap().to_string(), "-0.0001 SYM");
     }
 
     #[test]
-    #[should_panic(expected = "multiplication overflow")]
-    fn test_mul_assign_overflow() {
-        let mut asset1 = Asset {
-            amount: Asset::MAX_AMOUNT,
-            symbol: Symbol::from("4,SYM"),
-        };
-        asset1 *= 2;
+    fn test_display_precision() {
+        let asset = Asset::from_amount(12345, Symbol::from("4,SYM"));
+        assert_eq!(format!("{:.2}", asset), "1.23 SYM");
+        assert_eq!(format!("{:.6}", asset), "1.234500 SYM");
+        assert_eq!(format!("{:.4}", asset), "1.2345 SYM");
+        assert_eq!(format!("{:.0}", asset), "1 SYM");
+        assert_eq!(format!("{:.3}", asset), "1.235 SYM");
+        assert_eq!(format!("{:.3}", -asset), "-1.235 SYM");
+        assert_eq!(format!("{}", asset), "1.2345 SYM");
+
+        let asset = Asset::from_amount(-1, Symbol::from("4,SYM"));
+        assert_eq!(format!("{:.2}", asset), "0.00 SYM");
+        assert_eq!(format!("{:.0}", Asset::from_amount(5, Symbol::from("1,SYM"))), "1 SYM");
+        assert_eq!(format!("{:.2}", Asset::from_amount(5, Symbol::from("0,SYM"))), "5.00 SYM");
+        assert_eq!(format!("{:.1}", Asset::from_amount(i64::MAX, raw_symbol(60, "SYM"))), "0.0 SYM");
     }
 
     #[test]
-    #[should_panic(expected = "multiplication underflow")]
-    fn test_mul_assign_underflow() {
-        let mut asset1 = Asset {
-            amount: Asset::MAX_AMOUNT,
-            symbol: Symbol::from("4,SYM"),
-        };
-        asset1 *= -2;
+    fn test_debug() {
+        assert_eq!(format!("{:?}", Asset::from("1.0000 EOS")), r#"Asset("1.0000 EOS")"#);
+        assert_eq!(format!("{:?}", Asset::from("-0.01 USD")), r#"Asset("-0.01 USD")"#);
+        assert_eq!(format!("{:?}", Some(Asset::from("1 FOO"))), r#"Some(Asset("1 FOO"))"#);
+        assert!(format!("{:#?}", Asset::from("1.0000 EOS")).contains("\"1.0000 EOS\""));
     }
 
     #[test]
-    fn test_multiplication_operator() {
-        let asset1 = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
+    fn test_hex() {
+        let asset = Asset::from("1.0000 EOS");
+        assert_eq!(format!("{:x}", asset), "102700000000000004454f5300000000");
+        assert_eq!(format!("{:X}", asset), "102700000000000004454F5300000000");
+        assert_eq!(format!("{:x}", Asset::from("-0.0001 EOS")), "ffffffffffffffff04454f5300000000");
+        assert_eq!(format!("{:x}", Asset::new()), "0".repeat(32));
+    }
 
-        // Test positive multiplier
-        let asset2 = asset1 * 5;
-        assert_eq!(asset2.amount, 500);
-        assert_eq!(asset2.symbol, Symbol::from("4,SYM"));
+    #[test]
+    fn test_to_fixed() {
+        let asset = Asset::from("1.2345 SYM");
+        assert_eq!(asset.to_fixed(2), "1.23 SYM");
+        assert_eq!(asset.to_fixed(3), "1.235 SYM");
+        assert_eq!(asset.to_fixed(6), "1.234500 SYM");
+        assert_eq!(asset.to_fixed(4), "1.2345 SYM");
+        assert_eq!(asset.to_fixed(0), "1 SYM");
+        assert_eq!((-asset).to_fixed(2), "-1.23 SYM");
+        assert_eq!(Asset::from("5 SYM").to_fixed(2), "5.00 SYM");
+    }
 
-        // Test negative multiplier
-        let asset3 = -5 * asset1;
-        assert_eq!(asset3.amount, -500);
-        assert_eq!(asset3.symbol, Symbol::from("4,SYM"));
+    #[test]
+    fn test_to_string_high_precision() {
+        let s = "0.000000000000000000000000000000000000000000000000000000010000000000001 JIAYOUY";
+        assert_eq!(Asset::from_amount(10000000000001, raw_symbol(69, "JIAYOUY")).to_string(), s);
+        assert_eq!(
+            Asset::from_amount(-5, raw_symbol(20, "SYM")).to_string(),
+            "-0.00000000000000000005 SYM"
+        );
     }
 
     #[test]
-    fn test_div_assign() {
-        let mut asset1 = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
+    fn test_precision_not_representable() {
+        let sym = Symbol::from("18,SYM");
+        assert!(sym.is_precision_representable());
+        let asset = Asset::from_amount(1_500_000_000_000_000_000, sym);
+        assert_eq!(asset.value(), 1.5);
+        assert_eq!(asset.to_string(), "1.500000000000000000 SYM");
+        assert_eq!(format!("{:.1}", asset), "1.5 SYM");
+
+        let sym = raw_symbol(19, "SYM");
+        assert!(!sym.is_precision_representable());
+        let asset = Asset::from_amount(Asset::MAX_AMOUNT, sym);
+        assert!((asset.value() - 0.461_168_601_842_738_8).abs() < 1e-15);
+        assert_eq!(asset.to_string(), "0.4611686018427387903 SYM");
+        assert_eq!(format!("{:.2}", asset), "0.46 SYM");
+        assert_eq!(format!("{:.2}", Asset::from_amount(1, raw_symbol(255, "SYM"))), "0.00 SYM");
+        let asset = Asset::from_amount(-12345, raw_symbol(40, "SYM"));
+        assert_eq!(asset.to_string(), format!("-0.{}12345 SYM", "0".repeat(35)));
+    }
 
-        asset1 /= 2;
-        assert_eq!(asset1.amount, 50);
+    #[test]
+    fn test_to_string_out_of_range() {
+        let sym = Symbol::from("4,SYM");
+        assert_eq!(Asset::from_amount(i64::MIN, sym).to_string(), "-922337203685477.5808 SYM");
+        assert_eq!(Asset::from_amount(i64::MAX, sym).to_string(), "922337203685477.5807 SYM");
+        assert_eq!(format!("{:.2}", Asset::from_amount(i64::MIN, sym)), "-922337203685477.58 SYM");
+        assert_eq!(format!("{:.6}", Asset::from_amount(i64::MIN, sym)), "-922337203685477.580800 SYM");
+        assert_eq!(
+            Asset::from_amount(i64::MIN, Symbol::from("0,SYM")).to_string(),
+            "-9223372036854775808 SYM"
+        );
+        assert_eq!(
+            Asset::from_amount(i64::MIN, sym).to_string_grouped(','),
+            "-922,337,203,685,477.5808 SYM"
+        );
+        assert_eq!(Asset::from_amount(i64::MIN, sym).humanize(), "-922.34T SYM");
+    }
 
-        asset1 /= -5;
-        assert_eq!(asset1.amount, -10);
+    #[test]
+    fn test_to_string_zero_sign() {
+        let sym = Symbol::from("4,SYM");
+        assert_eq!(Asset::from_amount(-1, sym).to_string(), "-0.0001 SYM");
+        assert_eq!(Asset::from_amount(0, sym).to_string(), "0.0000 SYM");
+        assert_eq!(Asset::from_amount(1, sym).to_string(), "0.0001 SYM");
+        assert_eq!((-Asset::from_amount(0, sym)).to_string(), "0.0000 SYM");
+        assert_eq!((Asset::from_amount(-1, sym) + Asset::from_amount(1, sym)).to_string(), "0.0000 SYM");
+        assert_eq!("-0.0000 SYM".parse::<Asset>().unwrap().to_string(), "0.0000 SYM");
+        assert_eq!(Asset::from_amount(0, Symbol::from("0,SYM")).to_string(), "0 SYM");
     }
 
     #[test]
-    #[should_panic(expected = "divide by zero")]
-    fn test_asset_divide_by_zero() {
-        let mut asset1 = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
+    #[cfg(feature = "arbitrary")]
+    fn test_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        // deterministic pseudo-random input, so failures are reproducible
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let data: Vec<u8> = (0..4096)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                seed as u8
+            })
+            .collect();
 
-        asset1 /= 0;
+        let mut u = Unstructured::new(&data);
+        for _ in 0..100 {
+            let asset = Asset::arbitrary(&mut u).unwrap();
+            assert!(asset.is_valid(), "{:?}", asset);
+            assert!(asset.symbol.precision() <= 18);
+        }
     }
 
     #[test]
-    #[should_panic(expected = "signed division overflow")]
-    fn test_asset_signed_division_overflow() {
-        let mut asset1 = Asset {
-            amount: std::i64::MIN,
-            symbol: Symbol::from("4,SYM"),
-        };
+    #[cfg(feature = "quickcheck")]
+    fn test_quickcheck_round_trip() {
+        fn prop(asset: Asset) -> bool {
+            asset.is_valid()
+                && asset
+                    .to_string()
+                    .parse::<Asset>()
+                    .is_ok_and(|a| a.symbol == asset.symbol && a.amount == asset.amount)
+        }
+        quickcheck::quickcheck(prop as fn(Asset) -> bool);
+    }
 
-        asset1 /= -1;
+    #[test]
+    #[cfg(feature = "quickcheck")]
+    fn test_quickcheck_shrink() {
+        use quickcheck::Arbitrary;
+
+        let asset = Asset::from("-12.345 EOS");
+        let shrunk: Vec<Asset> = asset.shrink().collect();
+        assert_eq!(shrunk[0].amount, 0);
+        assert!(shrunk.iter().all(|a| a.is_valid()));
+        assert!(shrunk.iter().any(|a| a.amount == asset.amount && a.symbol == Symbol::from("0,EOS")));
+        assert!(shrunk.iter().any(|a| a.amount == asset.amount && a.symbol == Symbol::from("3,E")));
+        assert_eq!(Asset::from("0 A").shrink().count(), 0);
     }
 
     #[test]
-    fn test_divide_operator() {
-        let asset = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
+    #[cfg(feature = "schemars")]
+    fn test_json_schema() {
+        let schema = schemars::schema_for!(Asset);
+        assert_eq!(schema.get("type").and_then(|v| v.as_str()), Some("string"));
+        assert_eq!(schema.get("pattern").and_then(|v| v.as_str()), Some(r"^-?\d+(\.\d+)? [A-Z]{1,7}$"));
+    }
 
-        let result = asset / 2;
-        assert_eq!(result.amount, 50);
-        assert_eq!(result.symbol, Symbol::from("4,SYM"));
+    #[test]
+    fn test_eq_str() {
+        let asset = Asset::from("1.0000 EOS");
+        assert!(asset == "1.0000 EOS");
+        assert!(asset != "2.0000 EOS");
+        assert!(asset != "1.0000 FOO");
+        assert!(asset != "1.000 EOS");
+        assert!(asset != "1.0000EOS");
+        assert!(asset != "");
+        assert!(Asset::from("-0.0001 EOS") == "-0.0001 EOS");
     }
 
     #[test]
-    #[should_panic(expected = "divide by zero")]
-    fn test_divide_by_zero() {
-        let asset = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
+    fn test_as_ref() {
+        fn precision(symbol: impl AsRef<Symbol>) -> u8 {
+            symbol.as_ref().precision()
+        }
+        fn amount(amount: impl AsRef<i64>) -> i64 {
+            *amount.as_ref()
+        }
 
-        let _ = asset / 0;
+        let asset = Asset::from("1.0000 EOS");
+        assert_eq!(precision(asset), 4);
+        assert_eq!(precision(Symbol::from("2,USD")), 2);
+        assert_eq!(amount(asset), 10000);
+        assert_eq!(AsRef::<Asset>::as_ref(&asset).amount, 10000);
     }
 
     #[test]
-    #[should_panic(expected = "signed division overflow")]
-    fn test_signed_division_overflow() {
-        let asset = Asset {
-            amount: std::i64::MIN,
-            symbol: Symbol::from("4,SYM"),
-        };
-
-        let _ = asset / -1;
+    fn test_cmp_i64() {
+        let asset = Asset::from("1.0000 EOS");
+        assert!(asset > 0);
+        assert!(asset == 10000);
+        assert!(asset != 1);
+        assert!(asset <= 10000);
+        assert!(asset < 10001);
+        assert!(Asset::from("1.00 USD") == 100);
+
+        let negative = Asset::from("-0.0001 EOS");
+        assert!(negative < 0);
+        assert!(negative == -1);
+        assert!(negative > -2);
+        assert!(Asset::from("0.0000 EOS") >= 0);
     }
 
     #[test]
-    fn test_asset_divide_asset_operator() {
-        let asset1 = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
+    fn test_shift_amount_up() {
+        assert_eq!(shift_amount(12345, 4, 4), Ok(12345));
+        assert_eq!(shift_amount(12345, 4, 8), Ok(123450000));
+        assert_eq!(shift_amount(-1, 0, 18), Ok(-1_000_000_000_000_000_000));
+        assert_eq!(shift_amount(0, 0, 255), Ok(0));
+    }
 
-        let asset2 = Asset {
-            amount: 50,
-            symbol: Symbol::from("4,SYM"),
-        };
+    #[test]
+    fn test_shift_amount_down() {
+        assert_eq!(shift_amount(12345, 4, 2), Ok(123));
+        assert_eq!(shift_amount(-12345, 4, 2), Ok(-123));
+        assert_eq!(shift_amount(99, 4, 2), Ok(0));
+        assert_eq!(shift_amount(Asset::MAX_AMOUNT, 18, 0), Ok(4));
+        assert_eq!(shift_amount(i64::MAX, 255, 0), Ok(0));
+        // an out of range amount may be brought back in range
+        assert_eq!(shift_amount(i64::MIN, 1, 0), Ok(i64::MIN / 10));
+    }
 
-        let result = asset1 / asset2;
-        assert_eq!(result, 2);
+    #[test]
+    fn test_shift_amount_overflow() {
+        assert_eq!(shift_amount(Asset::MAX_AMOUNT, 0, 1), Err(ParseError::OutOfRange));
+        assert_eq!(shift_amount(Asset::MIN_AMOUNT, 4, 8), Err(ParseError::OutOfRange));
+        assert_eq!(shift_amount(1, 0, 19), Err(ParseError::OutOfRange));
+        assert_eq!(shift_amount(1, 0, 255), Err(ParseError::OutOfRange));
+        assert_eq!(shift_amount(i64::MIN, 4, 4), Err(ParseError::OutOfRange));
     }
 
     #[test]
-    #[should_panic(expected = "attempt to divide assets with different symbol")]
-    fn test_asset_divide_asset_operator_different_symbols() {
-        let asset1 = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
+    fn test_sort_assets() {
+        let mut v: Vec<Asset> = ["5.0000 EOS", "2.00000000 WAX", "-1.0000 EOS", "1.00000000 WAX", "3.0000 EOS"]
+            .iter()
+            .map(|s| Asset::from(*s))
+            .collect();
+        sort_assets(&mut v);
+        let sorted: Vec<String> = v.iter().map(|a| a.to_string()).collect();
+        assert_eq!(
+            sorted,
+            ["-1.0000 EOS", "3.0000 EOS", "5.0000 EOS", "1.00000000 WAX", "2.00000000 WAX"]
+        );
+    }
 
-        let asset2 = Asset {
-            amount: 50,
-            symbol: Symbol::from("5,SYM"),
-        };
+    #[test]
+    fn test_weighted_average() {
+        let values = [(Asset::from("1.0000 EOS"), 1), (Asset::from("2.0000 EOS"), 3)];
+        assert_eq!(weighted_average(&values).map(|a| a.to_string()), Some("1.7500 EOS".to_string()));
+        let values = [(Asset::from("1.0000 EOS"), 1), (Asset::from("0.0001 EOS"), 2)];
+        assert_eq!(weighted_average(&values).map(|a| a.amount), Some(3334));
+        let values = [(Asset::from("-1.0000 EOS"), 2), (Asset::from("0.0001 EOS"), 1)];
+        assert_eq!(weighted_average(&values).map(|a| a.amount), Some(-6666));
+        let values = [(Asset::from("5.0000 EOS"), 7)];
+        assert_eq!(weighted_average(&values).map(|a| a.amount), Some(50000));
+    }
 
-        let _ = asset1 / asset2;
+    #[test]
+    fn test_weighted_average_none() {
+        assert!(weighted_average(&[]).is_none());
+        assert!(weighted_average(&[(Asset::from("1.0000 EOS"), 0)]).is_none());
+        let values = [(Asset::from("1.0000 EOS"), 1), (Asset::from("2.0000 FOO"), 1)];
+        assert!(weighted_average(&values).is_none());
+        let values = [(Asset::from("1.0000 EOS"), 1), (Asset::from("2.000 EOS"), 1)];
+        assert!(weighted_average(&values).is_none());
+        let values = [(Asset::from_amount(i64::MAX, Symbol::from("4,EOS")), u64::MAX); 3];
+        assert!(weighted_average(&values).is_none());
     }
 
     #[test]
-    #[should_panic(expected = "divide by zero")]
-    fn test_asset_divide_asset_operator_divide_by_zero() {
-        let asset1 = Asset {
-            amount: 100,
-            symbol: Symbol::from("4,SYM"),
-        };
+    fn test_pack() {
+        let asset = Asset::from("-1.0000 EOS");
+        let packed = asset.to_packed();
+        assert_eq!(
+            packed,
+            [0xf0, 0xd8, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 4, b'E', b'O', b'S', 0, 0, 0, 0]
+        );
+        assert_eq!(Asset::from_packed(&packed).map(|a| a.to_string()), Ok(asset.to_string()));
+        assert_eq!(Asset::from_packed(&packed[..15]), Err(ParseError::UnexpectedEof));
+    }
 
-        let asset2 = Asset {
-            amount: 0,
-            symbol: Symbol::from("4,SYM"),
-        };
+    #[test]
+    fn test_split_proportionally() {
+        let amounts =
+            |asset: Asset, weights: &[u64]| -> Vec<i64> { asset.split_proportionally(weights).iter().map(|a| a.amount).collect() };
+        let pot = Asset::from_amount(100, Symbol::from("4,EOS"));
+        assert_eq!(amounts(pot, &[1, 1, 1]), [34, 33, 33]);
+        assert_eq!(amounts(pot, &[2, 1]), [67, 33]);
+        assert_eq!(amounts(pot, &[1]), [100]);
+        assert_eq!(amounts(pot, &[0, 1, 0]), [0, 100, 0]);
+        assert_eq!(amounts(pot, &[1, 1, 1, 1, 1, 1, 1]), [15, 15, 14, 14, 14, 14, 14]);
+        assert_eq!(amounts(-pot, &[1, 1, 1]), [-34, -33, -33]);
+        assert_eq!(amounts(Asset::from_amount(0, pot.symbol), &[3, 4]), [0, 0]);
+
+        let max = Asset::from_amount(Asset::MAX_AMOUNT, pot.symbol);
+        let split = max.split_proportionally(&[u64::MAX, 1, u64::MAX]);
+        assert_eq!(split.iter().map(|a| a.amount).sum::<i64>(), Asset::MAX_AMOUNT);
+        assert!(split.iter().all(|a| a.symbol == pot.symbol));
+    }
 
-        let _ = asset1 / asset2;
+    #[test]
+    #[should_panic(expected = "split weights must sum to more than zero")]
+    fn test_split_proportionally_zero_weights() {
+        let _ = Asset::from("1.0000 EOS").split_proportionally(&[0, 0]);
     }
 
     #[test]
-    fn test_to_string() {
-        assert_eq!(Asset::from_amount(-1000001, Symbol::from("4,SYM")).to_string(), "-100.0001 SYM");
-        assert_eq!(Asset::from_amount(10000, Symbol::from("4,SYM")).to_string(), "1.0000 SYM");
-        assert_eq!(Asset::from_amount(0, Symbol::from("4,SYM")).to_string(), "0.0000 SYM");
-        assert_eq!(Asset::from_amount(12345, Symbol::from("2,SYM")).to_string(), "123.45 SYM");
-        assert_eq!(Asset::from_amount(100, Symbol::from("0,SYM")).to_string(), "100 SYM");
-        assert_eq!(Asset::from_amount(0, Symbol::from("0,SYM")).to_string(), "0 SYM");
-        assert_eq!(Asset::from_amount(-100, Symbol::from("0,SYM")).to_string(), "-100 SYM");
+    fn test_from_amounts() {
+        let sym = Symbol::from("4,EOS");
         assert_eq!(
-            Asset::from_amount(0, Symbol::from("18,SYMBOLL")).to_string(),
-            "0.000000000000000000 SYMBOLL"
+            Asset::from_amounts([10000, 2500, -500], sym).map(|a| a.to_string()),
+            Ok("1.2000 EOS".to_string())
         );
+        assert_eq!(Asset::from_amounts(vec![], sym).map(|a| a.amount), Ok(0));
+        // intermediate sums may leave the range
         assert_eq!(
-            Asset::from_amount(-1000000000000000000, Symbol::from("18,SYMBOLL")).to_string(),
-            "-1.000000000000000000 SYMBOLL"
+            Asset::from_amounts([i64::MAX, i64::MAX, i64::MIN, i64::MIN], sym).map(|a| a.amount),
+            Ok(-2)
         );
     }
 
     #[test]
-    fn test_display() {
-        println!("{}", Asset::from_amount(10000, Symbol::from("4,SYM")))
+    fn test_from_amounts_overflow() {
+        let sym = Symbol::from("4,EOS");
+        assert_eq!(Asset::from_amounts([Asset::MAX_AMOUNT, 1], sym), Err(ParseError::OutOfRange));
+        assert_eq!(Asset::from_amounts([Asset::MIN_AMOUNT, -1], sym), Err(ParseError::OutOfRange));
+        assert_eq!(Asset::from_amounts([i64::MAX; 3], sym), Err(ParseError::OutOfRange));
     }
 
     #[test]
-    fn test_from_str() {
-        assert_eq!(Asset::from_amount(10000, Symbol::from("4,SYM")), "1.0000 SYM".parse().unwrap());
-        assert_eq!(Asset::from_amount(100, Symbol::from("0,SYM")), "100 SYM".parse().unwrap());
-        assert_eq!(Asset::from_amount(12345, Symbol::from("2,SYM")), "123.45 SYM".parse().unwrap());
+    fn test_from_amount_unchecked() {
+        const EOS: Asset = Asset::from_amount_trusted(10000, Symbol::eos());
+        // SAFETY: the amount is in range and the symbol is valid
+        let asset = unsafe { Asset::from_amount_unchecked(Asset::MAX_AMOUNT, Symbol::eos()) };
+        assert!(asset.is_valid());
+        assert_eq!(asset.amount, Asset::from_amount(Asset::MAX_AMOUNT, Symbol::eos()).amount);
+        assert_eq!(EOS.to_string(), "1.0000 EOS");
+    }
+
+    #[test]
+    fn test_pack_vec() {
+        let assets = vec![Asset::from("1.0000 EOS"), Asset::from("-2.00000000 WAX"), Asset::from("3 FOO")];
+        let packed = assets.to_packed();
+        assert_eq!(packed.len(), 1 + 3 * 16);
+        assert_eq!(packed[0], 3);
+        assert_eq!(packed[1..17], assets[0].to_packed());
+        let unpacked = Vec::<Asset>::from_packed(&packed).unwrap();
+        assert_eq!(unpacked.len(), 3);
+        for (a, b) in assets.iter().zip(unpacked.iter()) {
+            assert_eq!(a.to_string(), b.to_string());
+        }
+    }
+
+    #[test]
+    fn test_pack_option() {
+        let asset = Asset::from("1.0000 EOS");
+        let packed = Some(asset).to_packed();
+        assert_eq!(packed[0], 1);
+        assert_eq!(packed[1..], asset.to_packed());
+        let unpacked = Option::<Asset>::from_packed(&packed).unwrap();
+        assert_eq!(unpacked.map(|a| a.to_string()), Some(asset.to_string()));
+
+        let packed = None::<Asset>.to_packed();
+        assert_eq!(packed, [0]);
+        assert!(Option::<Asset>::from_packed(&packed).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_try_from_parts() {
+        let asset = Asset::try_from_parts(12345, 4, "EOS").unwrap();
+        assert_eq!(asset.amount, 12345);
+        assert_eq!(asset.symbol, Symbol::from("4,EOS"));
+        assert_eq!(asset.to_string(), "1.2345 EOS");
+        assert_eq!(Asset::try_from_parts(-5, 0, "A").unwrap().to_string(), "-5 A");
         assert_eq!(
-            Asset::from_amount(-1000001, Symbol::from("4,SYM")),
-            "-100.0001 SYM".parse().unwrap()
+            Asset::try_from_parts(Asset::MAX_AMOUNT, 18, "ABCDEFG").unwrap().amount,
+            Asset::MAX_AMOUNT
         );
-        assert_eq!(Asset::from_amount(0, Symbol::from("0,SYM")), "0 SYM".parse().unwrap());
-        assert_eq!(Asset::from_amount(0, Symbol::from("4,SYM")), "0.0000 SYM".parse().unwrap());
-        assert_eq!(Asset::from_amount(1, Symbol::from("4,SYM")), "0.0001 SYM".parse().unwrap());
+    }
+
+    #[test]
+    fn test_try_from_parts_failed() {
         assert_eq!(
-            Asset::from_amount(-1000000000000000000, Symbol::from("18,SYMBOLL")),
-            "-1.000000000000000000 SYMBOLL".parse().unwrap()
+            Asset::try_from_parts(1, 4, "eos"),
+            Err(ParseError::BadSymbolCode("eos".to_string()))
         );
+        assert_eq!(Asset::try_from_parts(1, 4, ""), Err(ParseError::BadSymbolCode("".to_string())));
         assert_eq!(
-            Asset::from_amount(10000000000001, Symbol::from("69,JIAYOUY")),
-            "0.000000000000000000000000000000000000000000000000000000010000000000001 JIAYOUY"
-                .parse()
-                .unwrap()
+            Asset::try_from_parts(1, 4, "ABCDEFGH"),
+            Err(ParseError::BadSymbolCode("ABCDEFGH".to_string()))
+        );
+        assert_eq!(Asset::try_from_parts(1, 19, "EOS"), Err(ParseError::BadPrecision("19".to_string())));
+        assert_eq!(
+            Asset::try_from_parts(i64::MIN, 4, "EOS"),
+            Err(ParseError::BadAmount {
+                input: i64::MIN.to_string(),
+                position: None
+            })
+        );
+        assert_eq!(
+            Asset::try_from_parts(Asset::MAX_AMOUNT + 1, 4, "EOS"),
+            Err(ParseError::BadAmount {
+                input: (Asset::MAX_AMOUNT + 1).to_string(),
+                position: None
+            })
         );
     }
 
     #[test]
-    fn test_from_str_failed() {
-        assert_eq!("".parse::<Asset>(), Err(ParseError::BadFormat));
-        assert_eq!("-".parse::<Asset>(), Err(ParseError::BadFormat));
-        assert_eq!("- EOS".parse::<Asset>(), Err(ParseError::BadAmount("-".to_string())));
-        assert_eq!("1s EOS".parse::<Asset>(), Err(ParseError::BadAmount("1s".to_string())));
-        assert_eq!("1\nEOS".parse::<Asset>(), Err(ParseError::BadFormat));
-        assert_eq!("- 100 EOS".parse::<Asset>(), Err(ParseError::BadFormat));
-        assert_eq!("-".parse::<Asset>(), Err(ParseError::BadFormat));
-        assert_eq!("1.0000".parse::<Asset>(), Err(ParseError::BadFormat));
-        assert_eq!("10000".parse::<Asset>(), Err(ParseError::BadFormat));
+    fn test_try_from_f64() {
+        let sym = Symbol::from("4,EOS");
+        assert_eq!(Asset::try_from_f64(1.5, sym), Ok(Asset::from("1.5000 EOS")));
+        assert_eq!(Asset::try_from_f64(-1.5, sym), Ok(Asset::from("-1.5000 EOS")));
+        assert_eq!(Asset::try_from_f64(0.1, sym), Ok(Asset::from("0.1000 EOS")));
+        assert_eq!(Asset::try_from_f64(0.00005, sym), Ok(Asset::from("0.0000 EOS")));
+        assert_eq!(Asset::try_from_f64(0.00025, sym), Ok(Asset::from("0.0002 EOS")));
+        assert_eq!(Asset::try_from_f64(0.00035, sym), Ok(Asset::from("0.0004 EOS")));
+        // the scaled value is 1.4999999999999998, so it rounds down
+        assert_eq!(Asset::try_from_f64(0.00015, sym), Ok(Asset::from("0.0001 EOS")));
+        assert_eq!(Asset::try_from_f64(2.5, Symbol::from("0,EOS")), Ok(Asset::from("2 EOS")));
+        assert_eq!(Asset::try_from_f64(3.5, Symbol::from("0,EOS")), Ok(Asset::from("4 EOS")));
+        assert_eq!(Asset::try_from_f64(1e14, sym).map(|a| a.amount), Ok(1_000_000_000_000_000_000));
+    }
+
+    #[test]
+    fn test_try_from_f64_failed() {
+        let sym = Symbol::from("4,EOS");
         assert_eq!(
-            "10000 LONGSYMBOL".parse::<Asset>(),
-            Err(ParseError::BadSymbolCode("LONGSYMBOL".to_string()))
+            Asset::try_from_f64(f64::NAN, sym),
+            Err(ParseError::BadAmount {
+                input: "NaN".to_string(),
+                position: None
+            })
         );
         assert_eq!(
-            "-0.0000000000000000000000000000000000000000000000000004371526177016610288 \\u0005".parse::<Asset>(),
-            Err(ParseError::BadSymbolCode("\\u0005".to_string())),
+            Asset::try_from_f64(f64::INFINITY, sym),
+            Err(ParseError::BadAmount {
+                input: "inf".to_string(),
+                position: None
+            })
         );
+        assert!(Asset::try_from_f64(f64::NEG_INFINITY, sym).is_err());
+        assert_eq!(Asset::try_from_f64(1e15, sym), Err(ParseError::OutOfRange));
+        assert_eq!(Asset::try_from_f64(-1e15, sym), Err(ParseError::OutOfRange));
+        assert_eq!(Asset::try_from_f64(1.0, raw_symbol(19, "EOS")), Err(ParseError::OutOfRange));
     }
 
-    #[test]
-    fn test_value() {
-        let sym = Symbol::from("4,SYM");
-        assert_eq!(Asset::from_amount(15000, sym).value(), 1.5);
+    fn valid_symbol() -> impl Strategy<Value = Symbol> {
+        (0..=18u8, "[A-Z]{1,7}").prop_map(|(precision, code)| Symbol::from_precision(SymbolCode::from(code.as_str()), precision))
+    }
+
+    proptest! {
+        #[test]
+        fn add_sub_inverse(sym in valid_symbol(), a in Asset::MIN_AMOUNT / 2..=Asset::MAX_AMOUNT / 2, b in Asset::MIN_AMOUNT / 2..=Asset::MAX_AMOUNT / 2) {
+            let a = Asset::from_amount(a, sym);
+            let b = Asset::from_amount(b, sym);
+            prop_assert_eq!(a + b - b, a);
+        }
+
+        #[test]
+        fn mul_div_inverse(sym in valid_symbol(), a in -(1i64 << 40)..=(1i64 << 40), n in prop_oneof![-1_000_000i64..=-1, 1..=1_000_000i64]) {
+            let a = Asset::from_amount(a, sym);
+            prop_assert_eq!((a * n) / n, a);
+        }
+
+        #[test]
+        fn double_neg(sym in valid_symbol(), a in Asset::MIN_AMOUNT..=Asset::MAX_AMOUNT) {
+            let a = Asset::from_amount(a, sym);
+            prop_assert_eq!(-(-a), a);
+        }
+
+        #[test]
+        fn to_string_parse_roundtrip(sym in valid_symbol(), a in Asset::MIN_AMOUNT..=Asset::MAX_AMOUNT) {
+            let a = Asset::from_amount(a, sym);
+            prop_assert!(a.is_valid());
+            prop_assert_eq!(a.to_string().parse::<Asset>(), Ok(a));
+        }
     }
 }
@@ -0,0 +1,94 @@
+use crate::{Asset, ParseError};
+
+/// The `AssetBuilder` struct builds an `Asset` from its components, validating each of them
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Asset, AssetBuilder};
+///
+/// let asset = AssetBuilder::new().amount(10000).precision(4).code("EOS").build().unwrap();
+/// assert_eq!(Asset::from("1.0000 EOS"), asset);
+/// ```
+#[derive(Eq, Clone, Debug, PartialEq, Default)]
+pub struct AssetBuilder {
+    amount: i64,
+    precision: u8,
+    code: String,
+}
+
+impl AssetBuilder {
+    /// Returns a new builder with a zero amount, a zero precision and no code
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the amount, in the smallest unit
+    #[inline]
+    #[must_use]
+    pub fn amount(mut self, amount: i64) -> Self {
+        self.amount = amount;
+        self
+    }
+
+    /// Sets the precision of the symbol
+    #[inline]
+    #[must_use]
+    pub fn precision(mut self, precision: u8) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets the symbol code, e.g. `"EOS"`
+    #[inline]
+    #[must_use]
+    pub fn code(mut self, code: &str) -> Self {
+        self.code = code.to_string();
+        self
+    }
+
+    /// Returns the asset, or the error of the first invalid component as `Asset::try_from_parts` does
+    pub fn build(&self) -> Result<Asset, ParseError> {
+        Asset::try_from_parts(self.amount, self.precision, &self.code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let asset = AssetBuilder::new().amount(10000).precision(4).code("EOS").build();
+        assert_eq!(asset, Ok(Asset::from("1.0000 EOS")));
+        assert_eq!(AssetBuilder::new().amount(-5).code("FOO").build(), Ok(Asset::from("-5 FOO")));
+
+        let builder = AssetBuilder::new().precision(2).code("USD");
+        assert_eq!(builder.clone().amount(1).build(), Ok(Asset::from("0.01 USD")));
+        assert_eq!(builder.amount(2).build(), Ok(Asset::from("0.02 USD")));
+    }
+
+    #[test]
+    fn test_build_failed() {
+        let builder = AssetBuilder::new().amount(10000).precision(4);
+        assert_eq!(
+            builder.clone().code("eos").build(),
+            Err(ParseError::BadSymbolCode("eos".to_string()))
+        );
+        assert_eq!(builder.clone().code("").build(), Err(ParseError::BadSymbolCode("".to_string())));
+        assert_eq!(builder.build(), Err(ParseError::BadSymbolCode("".to_string())));
+        assert_eq!(
+            AssetBuilder::new().precision(19).code("EOS").build(),
+            Err(ParseError::BadPrecision("19".to_string()))
+        );
+        assert_eq!(
+            AssetBuilder::new().amount(i64::MAX).code("EOS").build(),
+            Err(ParseError::BadAmount {
+                input: i64::MAX.to_string(),
+                position: None
+            })
+        );
+    }
+}
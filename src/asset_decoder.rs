@@ -0,0 +1,87 @@
+use crate::{Asset, ParseError, Unpack};
+
+/// The `AssetDecoder` struct reads packed assets one after another from a byte buffer
+///
+/// Each asset takes 16 bytes: its amount followed by its symbol. Decoding stops after the
+/// first error, e.g. a truncated asset at the end of the buffer.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Asset, AssetDecoder, Pack};
+///
+/// let mut buf = Asset::from("1.0000 EOS").to_packed();
+/// buf.extend(Asset::from("2.00 USD").to_packed());
+/// let assets: Vec<Asset> = AssetDecoder::new(&buf).collect::<Result<_, _>>().unwrap();
+/// assert_eq!("2.00 USD", assets[1].to_string());
+/// ```
+#[derive(Clone, Debug)]
+pub struct AssetDecoder<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> AssetDecoder<'a> {
+    #[inline]
+    #[must_use]
+    pub fn new(buf: &'a [u8]) -> Self {
+        AssetDecoder { buf, pos: 0 }
+    }
+
+    /// Returns the offset of the next asset in the buffer
+    #[inline]
+    #[must_use]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+}
+
+impl Iterator for AssetDecoder<'_> {
+    type Item = Result<Asset, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.buf.len() {
+            return None;
+        }
+        let asset = Asset::unpack(self.buf, &mut self.pos);
+        if asset.is_err() {
+            self.pos = self.buf.len();
+        }
+        Some(asset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pack;
+
+    #[test]
+    fn test_decode() {
+        let assets = [Asset::from("1.0000 EOS"), Asset::from("-2.00 USD"), Asset::from("3 FOO")];
+        let mut buf = Vec::new();
+        for asset in &assets {
+            asset.pack(&mut buf);
+        }
+
+        let mut decoder = AssetDecoder::new(&buf);
+        assert_eq!(decoder.next(), Some(Ok(assets[0])));
+        assert_eq!(decoder.position(), 16);
+        assert_eq!(decoder.next(), Some(Ok(assets[1])));
+        assert_eq!(decoder.next(), Some(Ok(assets[2])));
+        assert_eq!(decoder.position(), 48);
+        assert_eq!(decoder.next(), None);
+        assert_eq!(AssetDecoder::new(&[]).next(), None);
+    }
+
+    #[test]
+    fn test_decode_truncated() {
+        let mut buf = Asset::from("1.0000 EOS").to_packed();
+        buf.extend_from_slice(&[1, 2, 3]);
+
+        let mut decoder = AssetDecoder::new(&buf);
+        assert_eq!(decoder.next(), Some(Ok(Asset::from("1.0000 EOS"))));
+        assert_eq!(decoder.next(), Some(Err(ParseError::UnexpectedEof)));
+        assert_eq!(decoder.next(), None);
+    }
+}
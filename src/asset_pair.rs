@@ -0,0 +1,102 @@
+use std::fmt::{Display, Formatter};
+
+use crate::{Asset, Symbol};
+
+/// The `AssetPair` struct represents a base and a quote asset, e.g. the reserves of a DEX pool
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Asset, AssetPair};
+///
+/// let pair = AssetPair::new(Asset::from("100.0000 EOS"), Asset::from("250.00 USD"));
+/// assert_eq!(Some(2.5), pair.price());
+/// assert_eq!("EOS/USD", pair.to_string());
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct AssetPair {
+    pub base: Asset,
+    pub quote: Asset,
+}
+
+impl AssetPair {
+    #[inline]
+    #[must_use]
+    pub fn new(base: Asset, quote: Asset) -> Self {
+        AssetPair { base, quote }
+    }
+
+    /// Returns the price of one unit of base in quote, or `None` if the base amount is zero
+    #[must_use]
+    pub fn price(&self) -> Option<f64> {
+        if self.base.amount == 0 {
+            return None;
+        }
+        Some(self.quote.value() / self.base.value())
+    }
+
+    /// Returns the pair with base and quote swapped
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        AssetPair::new(self.quote, self.base)
+    }
+
+    /// Check if both assets are valid and their symbols differ
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.base.is_valid() && self.quote.is_valid() && self.base.symbol != self.quote.symbol
+    }
+
+    /// Check if the pair has the given base and quote symbols
+    #[inline]
+    #[must_use]
+    pub fn has_symbols(&self, base: Symbol, quote: Symbol) -> bool {
+        self.base.symbol == base && self.quote.symbol == quote
+    }
+
+    /// Check if `other` trades the same symbols in the same direction, so the two can be compared
+    #[inline]
+    #[must_use]
+    pub fn is_same_market(&self, other: &AssetPair) -> bool {
+        other.has_symbols(self.base.symbol, self.quote.symbol)
+    }
+}
+
+impl Display for AssetPair {
+    /// Formats the pair as `BASE/QUOTE`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.base.symbol.code(), self.quote.symbol.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price() {
+        let pair = AssetPair::new(Asset::from("100.0000 EOS"), Asset::from("250.00 USD"));
+        assert_eq!(pair.price(), Some(2.5));
+        assert_eq!(pair.inverse().price(), Some(0.4));
+        assert_eq!(pair.to_string(), "EOS/USD");
+        assert_eq!(pair.inverse().to_string(), "USD/EOS");
+
+        let empty = AssetPair::new(Asset::from("0.0000 EOS"), Asset::from("250.00 USD"));
+        assert_eq!(empty.price(), None);
+        assert_eq!(empty.inverse().price(), Some(0.0));
+    }
+
+    #[test]
+    fn test_symbols() {
+        let pair = AssetPair::new(Asset::from("100.0000 EOS"), Asset::from("250.00 USD"));
+        assert!(pair.is_valid());
+        assert!(pair.has_symbols(Symbol::from("4,EOS"), Symbol::from("2,USD")));
+        assert!(!pair.has_symbols(Symbol::from("2,USD"), Symbol::from("4,EOS")));
+        assert!(!pair.has_symbols(Symbol::from("4,EOS"), Symbol::from("4,USD")));
+        assert!(pair.is_same_market(&AssetPair::new(Asset::from("1.0000 EOS"), Asset::from("3.00 USD"))));
+        assert!(!pair.is_same_market(&pair.inverse()));
+        assert!(!AssetPair::new(Asset::from("1.0000 EOS"), Asset::from("1.0000 EOS")).is_valid());
+        assert!(!AssetPair::default().is_valid());
+    }
+}
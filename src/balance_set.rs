@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use crate::{Asset, Symbol};
+
+/// The `BalanceSet` struct keeps a running balance per symbol, e.g. of an account in an indexer
+///
+/// Balances may go negative, but like the `+=` and `-=` operators of `Asset` an update panics if
+/// a balance leaves the valid range.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Asset, BalanceSet, Symbol};
+///
+/// let mut balances = BalanceSet::new();
+/// balances.add(Asset::from("1.0000 EOS"));
+/// balances.add(Asset::from("2.5000 EOS"));
+/// balances.sub(Asset::from("1.00 USD"));
+/// assert_eq!(Some(Asset::from("3.5000 EOS")), balances.get(Symbol::from("4,EOS")));
+/// assert_eq!(Some(Asset::from("-1.00 USD")), balances.get(Symbol::from("2,USD")));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct BalanceSet(HashMap<Symbol, Asset>);
+
+impl BalanceSet {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        BalanceSet(HashMap::new())
+    }
+
+    /// Adds `asset` to the balance of its symbol, starting from zero
+    pub fn add(&mut self, asset: Asset) {
+        *self.entry(asset.symbol) += asset;
+    }
+
+    /// Subtracts `asset` from the balance of its symbol, starting from zero
+    pub fn sub(&mut self, asset: Asset) {
+        *self.entry(asset.symbol) -= asset;
+    }
+
+    /// Returns the balance of `symbol`, or `None` if no asset of that symbol was added or subtracted
+    #[inline]
+    #[must_use]
+    pub fn get(&self, symbol: Symbol) -> Option<Asset> {
+        self.0.get(&symbol).copied()
+    }
+
+    /// Returns the balances, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = &Asset> {
+        self.0.values()
+    }
+
+    fn entry(&mut self, symbol: Symbol) -> &mut Asset {
+        self.0.entry(symbol).or_insert_with(|| Asset::zero(symbol))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_sub() {
+        let mut balances = BalanceSet::new();
+        balances.add(Asset::from("1.0000 EOS"));
+        balances.add(Asset::from("0.50 USD"));
+        balances.add(Asset::from("2.0000 EOS"));
+        balances.sub(Asset::from("1.50 USD"));
+
+        assert_eq!(balances.get(Symbol::from("4,EOS")), Some(Asset::from("3.0000 EOS")));
+        assert_eq!(balances.get(Symbol::from("2,USD")), Some(Asset::from("-1.00 USD")));
+        assert_eq!(balances.get(Symbol::from("4,USD")), None);
+
+        let mut all: Vec<String> = balances.iter().map(Asset::to_string).collect();
+        all.sort();
+        assert_eq!(all, ["-1.00 USD", "3.0000 EOS"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "addition overflow")]
+    fn test_add_overflow() {
+        let mut balances = BalanceSet::new();
+        balances.add(Asset::from_amount(Asset::MAX_AMOUNT, Symbol::from("4,EOS")));
+        balances.add(Asset::from("0.0001 EOS"));
+    }
+}
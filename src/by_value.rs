@@ -0,0 +1,70 @@
+use std::cmp::Ordering;
+
+use crate::Asset;
+
+/// The `ByValue` struct orders assets by `value()`, whatever their symbols, e.g. in a
+/// `BinaryHeap` of the largest transfers
+///
+/// Values are `f64` compared with `total_cmp`, so amounts that differ beyond 53 bits of
+/// precision may compare equal. Unlike comparing assets directly this never panics.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BinaryHeap;
+///
+/// use antelope::{Asset, ByValue};
+///
+/// let mut heap = BinaryHeap::new();
+/// heap.push(ByValue(Asset::from("1.0000 EOS")));
+/// heap.push(ByValue(Asset::from("2.50 USD")));
+/// assert_eq!(Some(ByValue(Asset::from("2.50 USD"))), heap.pop());
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct ByValue(pub Asset);
+
+impl PartialEq for ByValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ByValue {}
+
+impl PartialOrd for ByValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.value().total_cmp(&other.0.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BinaryHeap;
+
+    use super::*;
+
+    #[test]
+    fn test_heap() {
+        let mut heap: BinaryHeap<ByValue> = ["1.0000 EOS", "25.00 USD", "-3 SYS", "2.50000000 BTC"]
+            .into_iter()
+            .map(|s| ByValue(Asset::from(s)))
+            .collect();
+        assert_eq!(heap.pop().map(|v| v.0.to_string()), Some("25.00 USD".to_string()));
+        assert_eq!(heap.pop().map(|v| v.0.to_string()), Some("2.50000000 BTC".to_string()));
+        assert_eq!(heap.pop().map(|v| v.0.to_string()), Some("1.0000 EOS".to_string()));
+        assert_eq!(heap.pop().map(|v| v.0.to_string()), Some("-3 SYS".to_string()));
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn test_cmp_across_symbols() {
+        assert_eq!(ByValue(Asset::from("1.0000 EOS")), ByValue(Asset::from("1.00 USD")));
+        assert!(ByValue(Asset::from("0.0001 EOS")) > ByValue(Asset::from("0 SYS")));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::ParseError;
+
 /// Checks that a condition is true, and panics if it is not.
 ///
 /// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/check.hpp>
@@ -8,17 +10,39 @@
 /// ```
 /// use antelope::check::check;
 ///
-/// #[test]
 /// check(true, "This should not panic");
+/// ```
+///
+/// ```should_panic
+/// use antelope::check::check;
 ///
-/// #[test]
-/// #[should_panic]
 /// check(false, "This should panic");
 /// ```
 pub fn check(predicate: bool, message: &str) {
     assert!(predicate, "{}", message)
 }
 
+/// Checks that a condition is true, and returns `err` if it is not.
+///
+/// The non-panicking counterpart of [`check`], for host-side code such as indexers where a bad
+/// input must not abort the process.
+///
+/// # Examples
+/// ```
+/// use antelope::check::try_check;
+/// use antelope::ParseError;
+///
+/// assert_eq!(Ok(()), try_check(true, ParseError::OutOfRange));
+/// assert_eq!(Err(ParseError::OutOfRange), try_check(false, ParseError::OutOfRange));
+/// ```
+pub fn try_check(predicate: bool, err: ParseError) -> Result<(), ParseError> {
+    if predicate {
+        Ok(())
+    } else {
+        Err(err)
+    }
+}
+
 #[cfg(test)]
 mod check_tests {
     use super::*;
@@ -33,4 +57,10 @@ mod check_tests {
     fn test_check_false() {
         check(false, "This should panic");
     }
+
+    #[test]
+    fn test_try_check() {
+        assert_eq!(try_check(true, ParseError::BadFormat), Ok(()));
+        assert_eq!(try_check(false, ParseError::BadFormat), Err(ParseError::BadFormat));
+    }
 }
@@ -0,0 +1,178 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::serializer::read_bytes;
+use crate::{Pack, ParseError, Unpack};
+
+/// The `Checksum256` struct represents the ABI `checksum256` type, e.g. a SHA-256 digest
+///
+/// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/fixed_bytes.hpp>
+///
+/// # Examples
+///
+/// ```
+/// use antelope::Checksum256;
+///
+/// let chain_id: Checksum256 = "aca376f206b8fc25a6ed44dbdc66547c36c6c33e3a119ffbeaef943642f0e906".parse().unwrap();
+/// assert_eq!(0xac, chain_id.raw()[0]);
+/// assert_eq!("aca376f206b8fc25a6ed44dbdc66547c36c6c33e3a119ffbeaef943642f0e906", chain_id.to_string());
+/// ```
+#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Default, Hash)]
+pub struct Checksum256([u8; 32]);
+
+impl Checksum256 {
+    /// Returns the raw bytes
+    #[inline]
+    #[must_use]
+    pub fn raw(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Returns the SHA-256 digest of `data`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::Checksum256;
+    ///
+    /// assert_eq!(
+    ///     "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+    ///     Checksum256::hash(b"").to_string()
+    /// );
+    /// ```
+    #[cfg(feature = "crypto")]
+    #[must_use]
+    pub fn hash(data: &[u8]) -> Self {
+        use sha2::Digest;
+        Checksum256(sha2::Sha256::digest(data).into())
+    }
+
+    /// Returns the transaction id of a packed transaction, the SHA-256 digest of its bytes
+    ///
+    /// This is the id explorers show, unlike the chain-specific signing digest of
+    /// `Transaction::id`
+    #[cfg(feature = "crypto")]
+    #[must_use]
+    pub fn from_transaction_bytes(packed_trx: &[u8]) -> Self {
+        Checksum256::hash(packed_trx)
+    }
+
+    /// Returns the hex string with a `0x` prefix, e.g. for explorer output
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::Checksum256;
+    ///
+    /// assert_eq!(format!("0x{}", "00".repeat(32)), Checksum256::default().to_hex_prefixed());
+    /// ```
+    #[must_use]
+    pub fn to_hex_prefixed(&self) -> String {
+        format!("0x{}", self)
+    }
+}
+
+impl From<[u8; 32]> for Checksum256 {
+    #[inline]
+    fn from(bytes: [u8; 32]) -> Self {
+        Checksum256(bytes)
+    }
+}
+
+impl From<Checksum256> for [u8; 32] {
+    #[inline]
+    fn from(value: Checksum256) -> Self {
+        value.0
+    }
+}
+
+impl Display for Checksum256 {
+    /// Formats the bytes as 64 lowercase hex digits
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Checksum256 {
+    type Err = ParseError;
+
+    /// Parses 64 hex digits, with an optional `0x` prefix
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix("0x").unwrap_or(s);
+        if s.len() != 64 || !s.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ParseError::BadFormat);
+        }
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).map_err(|_| ParseError::BadFormat)?;
+        }
+        Ok(Checksum256(bytes))
+    }
+}
+
+impl Pack for Checksum256 {
+    fn pack(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.0);
+    }
+}
+
+impl Unpack for Checksum256 {
+    fn unpack(data: &[u8], pos: &mut usize) -> Result<Self, ParseError> {
+        read_bytes::<32>(data, pos).map(Checksum256)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHAIN_ID: &str = "aca376f206b8fc25a6ed44dbdc66547c36c6c33e3a119ffbeaef943642f0e906";
+
+    #[test]
+    fn test_from_str() {
+        let checksum = CHAIN_ID.parse::<Checksum256>().unwrap();
+        assert_eq!(checksum.raw()[..4], [0xac, 0xa3, 0x76, 0xf2]);
+        assert_eq!(checksum.to_string(), CHAIN_ID);
+        assert_eq!(
+            CHAIN_ID.to_uppercase().parse::<Checksum256>().map(|c| c.to_string()),
+            Ok(CHAIN_ID.to_string())
+        );
+        assert_eq!("".parse::<Checksum256>(), Err(ParseError::BadFormat));
+        assert_eq!(CHAIN_ID[1..].parse::<Checksum256>(), Err(ParseError::BadFormat));
+        assert_eq!(CHAIN_ID.replace('a', "g").parse::<Checksum256>(), Err(ParseError::BadFormat));
+        assert_eq!(format!("+{}", &CHAIN_ID[1..]).parse::<Checksum256>(), Err(ParseError::BadFormat));
+    }
+
+    #[test]
+    fn test_hex_prefixed() {
+        let checksum = CHAIN_ID.parse::<Checksum256>().unwrap();
+        assert_eq!(format!("0x{}", CHAIN_ID).parse::<Checksum256>(), Ok(checksum));
+        assert_eq!(checksum.to_hex_prefixed(), format!("0x{}", CHAIN_ID));
+        assert_eq!(checksum.to_string(), CHAIN_ID);
+        assert_eq!(checksum.to_hex_prefixed().parse::<Checksum256>(), Ok(checksum));
+        assert_eq!(format!("0x0x{}", CHAIN_ID).parse::<Checksum256>(), Err(ParseError::BadFormat));
+        assert_eq!(format!("0x{}", &CHAIN_ID[2..]).parse::<Checksum256>(), Err(ParseError::BadFormat));
+    }
+
+    #[test]
+    fn test_pack_unpack() {
+        let checksum = Checksum256::from([7; 32]);
+        assert_eq!(checksum.to_packed(), [7; 32]);
+        assert_eq!(Checksum256::from_packed(&[7; 32]), Ok(checksum));
+        assert_eq!(Checksum256::from_packed(&[7; 31]), Err(ParseError::UnexpectedEof));
+        assert_eq!(<[u8; 32]>::from(checksum), [7; 32]);
+    }
+
+    #[test]
+    #[cfg(feature = "crypto")]
+    fn test_hash() {
+        assert_eq!(
+            Checksum256::hash(b"abc").to_string(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(Checksum256::from_transaction_bytes(b"abc"), Checksum256::hash(b"abc"));
+    }
+}
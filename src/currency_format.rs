@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use crate::SymbolCode;
+
+/// The `CurrencyFormat` struct maps symbol codes to the glyphs they are conventionally shown with,
+/// e.g. `$12.34` for USD-pegged tokens, see `Asset::format_currency`
+///
+/// A registered code is shown as `prefix`, the number, then `suffix`, in place of the code.
+/// Codes that are not registered keep the plain `Display` form.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Asset, CurrencyFormat, SymbolCode};
+///
+/// let mut fmt = CurrencyFormat::new();
+/// fmt.register(SymbolCode::from("USD"), "$", "");
+/// assert_eq!("$12.34", Asset::from("12.34 USD").format_currency(&fmt));
+/// assert_eq!("1.0000 EOS", Asset::from("1.0000 EOS").format_currency(&fmt));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CurrencyFormat {
+    glyphs: HashMap<SymbolCode, (String, String)>,
+}
+
+impl CurrencyFormat {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the glyphs of `code`, either of which may be empty, replacing any previous ones
+    pub fn register(&mut self, code: SymbolCode, prefix: &str, suffix: &str) {
+        self.glyphs.insert(code, (prefix.to_string(), suffix.to_string()));
+    }
+
+    /// Returns the `(prefix, suffix)` glyphs of `code`, or `None` if it is not registered
+    #[must_use]
+    pub fn glyphs(&self, code: SymbolCode) -> Option<(&str, &str)> {
+        self.glyphs.get(&code).map(|(prefix, suffix)| (prefix.as_str(), suffix.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let mut fmt = CurrencyFormat::new();
+        assert_eq!(fmt.glyphs(SymbolCode::from("USD")), None);
+        fmt.register(SymbolCode::from("USD"), "$", "");
+        fmt.register(SymbolCode::from("EUR"), "", " €");
+        assert_eq!(fmt.glyphs(SymbolCode::from("USD")), Some(("$", "")));
+        assert_eq!(fmt.glyphs(SymbolCode::from("EUR")), Some(("", " €")));
+        fmt.register(SymbolCode::from("USD"), "US$", "");
+        assert_eq!(fmt.glyphs(SymbolCode::from("USD")), Some(("US$", "")));
+    }
+}
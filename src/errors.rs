@@ -1,12 +1,27 @@
+use crate::Symbol;
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum ParseError {
     BadFormat,
-    BadAmount(String),
+    /// The amount is malformed, with the byte offset of the offending character if known
+    BadAmount {
+        input: String,
+        position: Option<usize>,
+    },
     BadSymbolCode(String),
     BadSymbol(String),
     BadPrecision(String),
     BadAsset(String),
     BadName(String),
+    /// The symbol parsed differs from the one expected, in code or precision
+    SymbolMismatch {
+        expected: Symbol,
+        found: Symbol,
+    },
+    UnexpectedEof,
+    OutOfRange,
+    /// An amount was divided by zero
+    DivisionByZero,
 }
 
 impl std::fmt::Display for ParseError {
@@ -15,10 +30,20 @@ impl std::fmt::Display for ParseError {
             ParseError::BadFormat => write!(f, "bad format"),
             ParseError::BadSymbolCode(s) => write!(f, "bad symbol code: {}", s),
             ParseError::BadSymbol(s) => write!(f, "bad symbol: {}", s),
-            ParseError::BadAmount(s) => write!(f, "bad amount: {}", s),
+            ParseError::BadAmount {
+                input,
+                position: Some(position),
+            } => write!(f, "bad amount: {} (at position {})", input, position),
+            ParseError::BadAmount { input, position: None } => write!(f, "bad amount: {}", input),
             ParseError::BadPrecision(s) => write!(f, "bad precision: {}", s),
             ParseError::BadAsset(s) => write!(f, "bad asset: {}", s),
             ParseError::BadName(s) => write!(f, "bad name: {}", s),
+            ParseError::SymbolMismatch { expected, found } => {
+                write!(f, "symbol mismatch: expected {}, found {}", expected, found)
+            }
+            ParseError::UnexpectedEof => write!(f, "unexpected end of data"),
+            ParseError::OutOfRange => write!(f, "amount out of range"),
+            ParseError::DivisionByZero => write!(f, "division by zero"),
         }
     }
 }
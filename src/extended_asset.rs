@@ -132,7 +132,6 @@ impl From<&str> for ExtendedAsset {
 
 impl AsRef<ExtendedAsset> for ExtendedAsset {
     #[inline]
-    #[must_use]
     fn as_ref(&self) -> &ExtendedAsset {
         self
     }
@@ -378,6 +377,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "non_panicking_eq"))]
     #[should_panic(expected = "comparison of assets with different symbols is not allowed")]
     fn test_ord_panic1() {
         let a = ExtendedAsset::from_amount(100, ExtendedSymbol::from_extended(Symbol::from("4,SYM"), Name::from("contract")));
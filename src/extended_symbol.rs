@@ -102,7 +102,6 @@ impl From<&str> for ExtendedSymbol {
 
 impl AsRef<ExtendedSymbol> for ExtendedSymbol {
     #[inline]
-    #[must_use]
     fn as_ref(&self) -> &ExtendedSymbol {
         self
     }
@@ -110,7 +109,6 @@ impl AsRef<ExtendedSymbol> for ExtendedSymbol {
 
 impl From<ExtendedSymbol> for bool {
     #[inline]
-    #[must_use]
     fn from(ext_sym: ExtendedSymbol) -> Self {
         ext_sym.contract.raw() != 0 && ext_sym.sym.raw() != 0
     }
@@ -143,8 +141,8 @@ mod tests {
 
         let s0 = Symbol::from_precision(SymbolCode::from("A"), 0);
         let s1 = Symbol::from_precision(SymbolCode::from("Z"), 0);
-        let s2 = Symbol::from_precision(SymbolCode::from("AAAAAAA"), 255);
-        let s3 = Symbol::from_precision(SymbolCode::from("ZZZZZZZ"), 255);
+        let s2 = Symbol::from_precision(SymbolCode::from("AAAAAAA"), Symbol::MAX_PRECISION);
+        let s3 = Symbol::from_precision(SymbolCode::from("ZZZZZZZ"), Symbol::MAX_PRECISION);
 
         //// constexpr ExtendedSymbol::from_extended(symbol, name)
         assert_eq!(ExtendedSymbol::from_extended(s0, n0).get_symbol().raw(), 16640);
@@ -155,9 +153,9 @@ mod tests {
         assert_eq!(ExtendedSymbol::from_extended(s0, n1).get_contract().value, 2882303761517117440);
         assert_eq!(ExtendedSymbol::from_extended(s1, n2).get_contract().value, 3458764513820540928);
         assert_eq!(ExtendedSymbol::from_extended(s1, n3).get_contract().value, 17870283321406128128);
-        assert_eq!(ExtendedSymbol::from_extended(s2, n4).get_symbol().raw(), 4702111234474983935);
+        assert_eq!(ExtendedSymbol::from_extended(s2, n4).get_symbol().raw(), 4702111234474983698);
         assert_eq!(ExtendedSymbol::from_extended(s2, n5).get_symbol().code().raw(), 18367622009667905);
-        assert_eq!(ExtendedSymbol::from_extended(s3, n6).get_symbol().raw(), 6510615555426900735);
+        assert_eq!(ExtendedSymbol::from_extended(s3, n6).get_symbol().raw(), 6510615555426900498);
         assert_eq!(ExtendedSymbol::from_extended(s3, n7).get_symbol().code().raw(), 25432092013386330);
         assert_eq!(ExtendedSymbol::from_extended(s2, n4).get_contract().value, 595056260442243615);
         assert_eq!(ExtendedSymbol::from_extended(s2, n5).get_contract().value, 2975281302211218015);
@@ -174,42 +172,42 @@ mod tests {
 
         let s0 = Symbol::from_precision(SymbolCode::from("A"), 0);
         let s1 = Symbol::from_precision(SymbolCode::from("Z"), 0);
-        let s2 = Symbol::from_precision(SymbolCode::from("AAAAAAA"), 255);
-        let s3 = Symbol::from_precision(SymbolCode::from("ZZZZZZZ"), 255);
+        let s2 = Symbol::from_precision(SymbolCode::from("AAAAAAA"), Symbol::MAX_PRECISION);
+        let s3 = Symbol::from_precision(SymbolCode::from("ZZZZZZZ"), Symbol::MAX_PRECISION);
 
         // -------------------------------------------------------------------------------
         // friend constexpr bool operator==(const ExtendedSymbol::from_extended(, const ExtendedSymbol::from_extended()
-        assert_eq!(ExtendedSymbol::from_extended(s0, n0) == ExtendedSymbol::from_extended(s0, n0), true);
-        assert_eq!(ExtendedSymbol::from_extended(s1, n3) == ExtendedSymbol::from_extended(s1, n3), true);
-        assert_eq!(ExtendedSymbol::from_extended(s2, n4) == ExtendedSymbol::from_extended(s2, n4), true);
-        assert_eq!(ExtendedSymbol::from_extended(s3, n7) == ExtendedSymbol::from_extended(s3, n7), true);
+        assert_eq!(ExtendedSymbol::from_extended(s0, n0), ExtendedSymbol::from_extended(s0, n0));
+        assert_eq!(ExtendedSymbol::from_extended(s1, n3), ExtendedSymbol::from_extended(s1, n3));
+        assert_eq!(ExtendedSymbol::from_extended(s2, n4), ExtendedSymbol::from_extended(s2, n4));
+        assert_eq!(ExtendedSymbol::from_extended(s3, n7), ExtendedSymbol::from_extended(s3, n7));
     }
 
     #[test]
     fn test_cdt_4() {
         let s0 = Symbol::from_precision(SymbolCode::from("A"), 0);
         let s1 = Symbol::from_precision(SymbolCode::from("Z"), 0);
-        let s2 = Symbol::from_precision(SymbolCode::from("AAAAAAA"), 255);
-        let s3 = Symbol::from_precision(SymbolCode::from("ZZZZZZZ"), 255);
+        let s2 = Symbol::from_precision(SymbolCode::from("AAAAAAA"), Symbol::MAX_PRECISION);
+        let s3 = Symbol::from_precision(SymbolCode::from("ZZZZZZZ"), Symbol::MAX_PRECISION);
 
         // friend constexpr bool operator!=(const ExtendedSymbol::from_extended(, const ExtendedSymbol::from_extended();
-        assert_eq!(ExtendedSymbol::from_symbol(Symbol::new()) != ExtendedSymbol::from_symbol(s0), true);
-        assert_eq!(ExtendedSymbol::from_symbol(s0) != ExtendedSymbol::from_symbol(s1), true);
-        assert_eq!(ExtendedSymbol::from_symbol(s1) != ExtendedSymbol::from_symbol(s2), true);
-        assert_eq!(ExtendedSymbol::from_symbol(s2) != ExtendedSymbol::from_symbol(s3), true);
+        assert!(ExtendedSymbol::from_symbol(Symbol::new()) != ExtendedSymbol::from_symbol(s0));
+        assert!(ExtendedSymbol::from_symbol(s0) != ExtendedSymbol::from_symbol(s1));
+        assert!(ExtendedSymbol::from_symbol(s1) != ExtendedSymbol::from_symbol(s2));
+        assert!(ExtendedSymbol::from_symbol(s2) != ExtendedSymbol::from_symbol(s3));
     }
 
     #[test]
     fn test_cdt_5() {
         let s0 = Symbol::from_precision(SymbolCode::from("A"), 0);
         let s1 = Symbol::from_precision(SymbolCode::from("Z"), 0);
-        let s2 = Symbol::from_precision(SymbolCode::from("AAAAAAA"), 255);
-        let s3 = Symbol::from_precision(SymbolCode::from("ZZZZZZZ"), 255);
+        let s2 = Symbol::from_precision(SymbolCode::from("AAAAAAA"), Symbol::MAX_PRECISION);
+        let s3 = Symbol::from_precision(SymbolCode::from("ZZZZZZZ"), Symbol::MAX_PRECISION);
 
         // friend constexpr bool operator<(const ExtendedSymbol::from_extended(, const ExtendedSymbol::from_extended();
-        assert_eq!(ExtendedSymbol::new() < ExtendedSymbol::from_symbol(s0), true);
-        assert_eq!(ExtendedSymbol::new() < ExtendedSymbol::from_symbol(s1), true);
-        assert_eq!(ExtendedSymbol::new() < ExtendedSymbol::from_symbol(s2), true);
-        assert_eq!(ExtendedSymbol::new() < ExtendedSymbol::from_symbol(s3), true);
+        assert!(ExtendedSymbol::new() < ExtendedSymbol::from_symbol(s0));
+        assert!(ExtendedSymbol::new() < ExtendedSymbol::from_symbol(s1));
+        assert!(ExtendedSymbol::new() < ExtendedSymbol::from_symbol(s2));
+        assert!(ExtendedSymbol::new() < ExtendedSymbol::from_symbol(s3));
     }
 }
@@ -0,0 +1,259 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::serializer::read_bytes;
+use crate::{Pack, ParseError, Unpack};
+
+const MANTISSA_BITS: u32 = 112;
+const MANTISSA_MASK: u128 = (1 << MANTISSA_BITS) - 1;
+const EXPONENT_MASK: u128 = 0x7fff;
+const EXPONENT_BIAS: i32 = 16383;
+
+/// The `Float128` struct represents the ABI `float128` type
+///
+/// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/serialize.hpp>
+///
+/// Stores the raw IEEE-754 binary128 bytes (little-endian) without loss. No arithmetic is provided;
+/// the value can be viewed as an `f64` with [`Float128::to_f64`], which rounds to the nearest `f64`.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::Float128;
+///
+/// let f = Float128::from(1.5);
+/// assert_eq!(1.5, f.to_f64());
+/// assert_eq!("0x0000000000000000000000000080ff3f", f.to_string());
+/// ```
+#[derive(Eq, Copy, Clone, Debug, PartialEq, Default, Hash)]
+pub struct Float128([u8; 16]);
+
+impl Float128 {
+    /// Returns the raw little-endian bytes
+    #[inline]
+    #[must_use]
+    pub fn raw(&self) -> [u8; 16] {
+        self.0
+    }
+
+    /// Returns the nearest `f64` value
+    ///
+    /// The conversion is lossy: binary128 has a 112-bit mantissa and a 15-bit exponent, so values
+    /// are rounded to the nearest `f64` (ties to even), overflow to infinity and underflow to zero.
+    /// NaN payloads keep their most significant bits.
+    #[must_use]
+    pub fn to_f64(&self) -> f64 {
+        let bits = u128::from_le_bytes(self.0);
+        let sign = ((bits >> 127) as u64) << 63;
+        let exponent = ((bits >> MANTISSA_BITS) & EXPONENT_MASK) as i32;
+        let mantissa = bits & MANTISSA_MASK;
+
+        if exponent == EXPONENT_MASK as i32 {
+            if mantissa == 0 {
+                return f64::from_bits(sign | 0x7ff0_0000_0000_0000);
+            }
+            return f64::from_bits(sign | 0x7ff8_0000_0000_0000 | (mantissa >> 60) as u64);
+        }
+        if exponent == 0 {
+            // zero, or a subnormal far below the smallest f64
+            return f64::from_bits(sign);
+        }
+
+        let e = exponent - EXPONENT_BIAS;
+        if e > 1023 {
+            return f64::from_bits(sign | 0x7ff0_0000_0000_0000);
+        }
+        if e >= -1022 {
+            let m = round_shift(mantissa, 60);
+            // rounding may carry into the exponent, which the addition handles naturally
+            let bits = (((e + 1023) as u64) << 52) + m as u64;
+            return f64::from_bits(sign | bits.min(0x7ff0_0000_0000_0000));
+        }
+
+        // f64 subnormal: value = m * 2^-1074, with the implicit leading bit made explicit
+        let shift = (-(e + 962)) as u32;
+        if shift > MANTISSA_BITS + 2 {
+            return f64::from_bits(sign);
+        }
+        f64::from_bits(sign | round_shift((1 << MANTISSA_BITS) | mantissa, shift) as u64)
+    }
+}
+
+/// Shifts `value` right by `shift` bits, rounding to nearest with ties to even
+fn round_shift(value: u128, shift: u32) -> u128 {
+    let result = value >> shift;
+    let remainder = value & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if remainder > half || (remainder == half && result & 1 == 1) {
+        result + 1
+    } else {
+        result
+    }
+}
+
+impl From<f64> for Float128 {
+    /// Every `f64` is exactly representable as binary128, so this conversion is lossless
+    fn from(value: f64) -> Self {
+        let bits = value.to_bits();
+        let sign = ((bits >> 63) as u128) << 127;
+        let exponent = ((bits >> 52) & 0x7ff) as i32;
+        let mantissa = (bits & 0x000f_ffff_ffff_ffff) as u128;
+
+        let bits = if exponent == 0x7ff {
+            sign | (EXPONENT_MASK << MANTISSA_BITS) | (mantissa << 60)
+        } else if exponent == 0 && mantissa == 0 {
+            sign
+        } else if exponent == 0 {
+            // normalize the f64 subnormal so its leading bit becomes the implicit one
+            let shift = mantissa.leading_zeros() - 75;
+            let e = -1022 - shift as i32 + EXPONENT_BIAS;
+            sign | ((e as u128) << MANTISSA_BITS) | (((mantissa << shift) & 0x000f_ffff_ffff_ffff) << 60)
+        } else {
+            let e = exponent - 1023 + EXPONENT_BIAS;
+            sign | ((e as u128) << MANTISSA_BITS) | (mantissa << 60)
+        };
+        Float128(bits.to_le_bytes())
+    }
+}
+
+impl From<[u8; 16]> for Float128 {
+    #[inline]
+    fn from(bytes: [u8; 16]) -> Self {
+        Float128(bytes)
+    }
+}
+
+impl From<Float128> for [u8; 16] {
+    #[inline]
+    fn from(value: Float128) -> Self {
+        value.0
+    }
+}
+
+impl Display for Float128 {
+    /// Formats the raw little-endian bytes as `0x`-prefixed hex, as nodeos does in JSON
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("0x")?;
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Float128 {
+    type Err = ParseError;
+
+    /// Parses 32 hex digits of raw little-endian bytes, with an optional `0x` prefix
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix("0x").unwrap_or(s);
+        if hex.len() != 32 || !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ParseError::BadFormat);
+        }
+        let mut bytes = [0u8; 16];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|_| ParseError::BadFormat)?;
+        }
+        Ok(Float128(bytes))
+    }
+}
+
+impl Pack for Float128 {
+    fn pack(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.0);
+    }
+}
+
+impl Unpack for Float128 {
+    fn unpack(data: &[u8], pos: &mut usize) -> Result<Self, ParseError> {
+        read_bytes::<16>(data, pos).map(Float128)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_bits(bits: u128) -> Float128 {
+        Float128::from(bits.to_le_bytes())
+    }
+
+    #[test]
+    fn test_pack_unpack() {
+        let bytes: [u8; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let f = Float128::from(bytes);
+        let packed = f.to_packed();
+        assert_eq!(packed, bytes.to_vec());
+        assert_eq!(Float128::from_packed(&packed), Ok(f));
+        assert_eq!(f.raw(), bytes);
+        assert_eq!(<[u8; 16]>::from(f), bytes);
+    }
+
+    #[test]
+    fn test_unpack_failed() {
+        assert_eq!(Float128::from_packed(&[0; 15]), Err(ParseError::UnexpectedEof));
+        assert_eq!(Float128::from_packed(&[0; 17]), Err(ParseError::BadFormat));
+    }
+
+    #[test]
+    fn test_from_f64() {
+        assert_eq!(Float128::from(0.0).raw(), [0; 16]);
+        assert_eq!(Float128::from(1.0), from_bits(0x3fff << 112));
+        assert_eq!(Float128::from(-2.0), from_bits((1 << 127) | (0x4000 << 112)));
+        assert_eq!(Float128::from(1.5), from_bits((0x3fff << 112) | (1 << 111)));
+        assert_eq!(Float128::from(f64::INFINITY), from_bits(0x7fff << 112));
+    }
+
+    #[test]
+    fn test_to_f64() {
+        for value in [
+            0.0,
+            -0.0,
+            1.0,
+            -1.5,
+            0.1,
+            std::f64::consts::PI,
+            1e300,
+            -1e-300,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            f64::MIN_POSITIVE / 1024.0,
+            5e-324,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ] {
+            let f = Float128::from(value);
+            assert_eq!(f.to_f64().to_bits(), value.to_bits(), "{}", value);
+        }
+        assert!(Float128::from(f64::NAN).to_f64().is_nan());
+    }
+
+    #[test]
+    fn test_to_f64_rounding() {
+        let one = 0x3fff << 112;
+        // exactly half an f64 ulp above 1.0 rounds to even
+        assert_eq!(from_bits(one | (1 << 59)).to_f64(), 1.0);
+        // just above half an ulp rounds up
+        assert_eq!(from_bits(one | (1 << 59) | 1).to_f64(), 1.0 + f64::EPSILON);
+        // beyond the f64 range
+        assert_eq!(from_bits(0x7ffe << 112).to_f64(), f64::INFINITY);
+        assert_eq!(from_bits(1 << 112).to_f64(), 0.0);
+        assert_eq!(from_bits(1).to_f64(), 0.0);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Float128::from(0.0).to_string(), "0x00000000000000000000000000000000");
+        assert_eq!(Float128::from(1.0).to_string(), "0x0000000000000000000000000000ff3f");
+    }
+
+    #[test]
+    fn test_from_str() {
+        let f = Float128::from(-1.5);
+        assert_eq!(f.to_string().parse::<Float128>(), Ok(f));
+        assert_eq!("0000000000000000000000000000ff3f".parse::<Float128>(), Ok(Float128::from(1.0)));
+        assert_eq!("0x00".parse::<Float128>(), Err(ParseError::BadFormat));
+        assert_eq!("0x0000000000000000000000000000ff3g".parse::<Float128>(), Err(ParseError::BadFormat));
+        assert_eq!("0x+000000000000000000000000000ff3f".parse::<Float128>(), Err(ParseError::BadFormat));
+    }
+}
@@ -64,3 +64,74 @@ pub use self::time_point::*;
 /// Modules for TimePoint type.
 pub mod time_point_sec;
 pub use self::time_point_sec::*;
+
+/// Binary serialization traits.
+pub mod serializer;
+pub use self::serializer::*;
+
+/// Modules for Float128 type.
+pub mod float128;
+pub use self::float128::*;
+
+/// Modules for VarUint32 type.
+pub mod varuint32;
+pub use self::varuint32::*;
+
+/// Modules for Checksum256 type.
+pub mod checksum256;
+pub use self::checksum256::*;
+
+/// Modules for PermissionLevel type.
+pub mod permission_level;
+pub use self::permission_level::*;
+
+/// Modules for Action type.
+pub mod action;
+pub use self::action::*;
+
+/// Modules for Transaction type.
+pub mod transaction;
+pub use self::transaction::*;
+
+/// Modules for Rounding type.
+pub mod rounding;
+pub use self::rounding::*;
+
+/// Modules for AssetPair type.
+pub mod asset_pair;
+pub use self::asset_pair::*;
+
+/// Helpers for `#[serde(with = ...)]`.
+#[cfg(feature = "serde")]
+pub mod serde;
+
+/// Modules for AssetBuilder type.
+pub mod asset_builder;
+pub use self::asset_builder::*;
+
+/// Modules for Ratio type.
+pub mod ratio;
+pub use self::ratio::*;
+
+/// Modules for AssetDecoder type.
+pub mod asset_decoder;
+pub use self::asset_decoder::*;
+
+/// Modules for Rate type.
+pub mod rate;
+pub use self::rate::*;
+
+/// Commonly used types, for `use antelope::prelude::*;`.
+pub mod prelude;
+
+/// Modules for BalanceSet type.
+pub mod balance_set;
+pub use self::balance_set::*;
+
+/// Modules for CurrencyFormat type.
+pub mod currency_format;
+pub use self::currency_format::*;
+
+/// Modules for ByValue type.
+pub mod by_value;
+pub use self::by_value::*;
@@ -5,7 +5,7 @@ use std::convert::From;
 use std::fmt;
 use std::str::FromStr;
 
-use crate::{check, ParseError};
+use crate::{check, Pack, ParseError, Unpack};
 
 pub const NAME_CHARS: [u8; 32] = *b".12345abcdefghijklmnopqrstuvwxyz";
 
@@ -48,7 +48,7 @@ impl Name {
      */
     #[must_use]
     #[inline]
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self { value: 0 }
     }
 
@@ -59,10 +59,85 @@ impl Name {
      */
     #[must_use]
     #[inline]
-    pub fn raw(&self) -> u64 {
+    pub const fn raw(&self) -> u64 {
         self.value
     }
 
+    /**
+     * Construct a name from its raw value
+     *
+     * @param value - The raw value of the name
+     */
+    #[must_use]
+    #[inline]
+    pub const fn from_u64(value: u64) -> Self {
+        Self { value }
+    }
+
+    /**
+     * Returns the raw value of the name, same as `raw()`
+     */
+    #[must_use]
+    #[inline]
+    pub const fn as_u64(&self) -> u64 {
+        self.value
+    }
+
+    /**
+     * Construct a name from a string at compile time, e.g. `const EOSIO: Name = Name::from_str_const("eosio");`
+     *
+     * Same encoding as `FromStr`, but an invalid name panics, which fails the build in a const context
+     *
+     * @param s - The name, up to 13 characters of `.12345a-z`, the 13th being at most `j`
+     */
+    #[must_use]
+    pub const fn from_str_const(s: &str) -> Self {
+        let bytes = s.as_bytes();
+        assert!(bytes.len() <= NAME_MAX_LEN, "name is longer than 13 characters");
+        let mut value = 0_u64;
+        let mut i = 0;
+        while i < bytes.len() {
+            let c = match bytes[i] {
+                b'.' => 0,
+                b'1'..=b'5' => bytes[i] - b'1' + 1,
+                b'a'..=b'z' => bytes[i] - b'a' + 6,
+                _ => panic!("name has an invalid character"),
+            };
+            if i < 12 {
+                value |= (c as u64) << (64 - 5 * (i + 1));
+            } else {
+                // the 13th character only has 4 bits
+                assert!(c <= 0x0F, "name has an invalid 13th character");
+                value |= c as u64;
+            }
+            i += 1;
+        }
+        Self { value }
+    }
+
+    /**
+     * Match the name against an account pattern
+     *
+     * The pattern is either an exact name, or has a single `*` wildcard standing for one or more
+     * dot-delimited segments at the start (`*.token`) or end (`eosio.*`). Any other use of `*`
+     * never matches.
+     *
+     * @param pattern - The pattern, e.g. "eosio.*"
+     * @return true - if the name matches the pattern
+     */
+    #[must_use]
+    pub fn matches_pattern(&self, pattern: &str) -> bool {
+        let name = self.to_string();
+        if let Some(prefix) = pattern.strip_suffix('*') {
+            // keeps the dot, e.g. "eosio."
+            prefix.ends_with('.') && !prefix.contains('*') && name.len() > prefix.len() && name.starts_with(prefix)
+        } else if let Some(suffix) = pattern.strip_prefix('*') {
+            suffix.starts_with('.') && !suffix.contains('*') && name.len() > suffix.len() && name.ends_with(suffix)
+        } else {
+            !pattern.contains('*') && name == pattern
+        }
+    }
+
     /**
      *  Returns the length of the %name
      */
@@ -308,6 +383,39 @@ impl From<Name> for bool {
     }
 }
 
+impl Pack for Name {
+    /**
+     * Packs the name as its 8-byte little-endian `uint64_t` value
+     */
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.value.pack(out);
+    }
+}
+
+impl Unpack for Name {
+    fn unpack(data: &[u8], pos: &mut usize) -> Result<Self, ParseError> {
+        u64::unpack(data, pos).map(Name::from)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Name {
+    /**
+     * Serializes the name as its string form, e.g. "eosio.token"
+     */
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Name {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -330,8 +438,10 @@ mod tests {
         assert_eq!(Name::from(u64::MAX).raw(), u64::MAX);
 
         // test that constexpr constructor is evaluated at compile time
-        assert_eq!(Name::from(0).value == 0, true);
-        assert_eq!(Name::from(Name::from(1)).value == 1, true);
+        assert_eq!(Name::from(0).value, 0);
+        #[allow(clippy::useless_conversion)]
+        let name = Name::from(Name::from(1));
+        assert_eq!(name.value, 1);
     }
 
     #[test]
@@ -365,7 +475,7 @@ mod tests {
         assert_eq!(Name::from("zzzzzzzzzzzzj").value, u64::MAX);
 
         // test that constexpr constructor is evaluated at compile time
-        assert_eq!(Name::from("1").value == 576460752303423488, true);
+        assert_eq!(Name::from("1").value, 576460752303423488);
     }
 
     #[test]
@@ -431,20 +541,23 @@ mod tests {
         assert_eq!(Name::from("a.my.account").prefix(), Name::from("a.my"));
         assert_eq!(Name::from("a.my.account").prefix().prefix(), Name::from("a"));
 
-        assert_eq!(Name::from("e.osioaccounj").prefix() == Name::from("e"), true);
+        assert_eq!(Name::from("e.osioaccounj").prefix(), Name::from("e"));
     }
 
     #[test]
     fn test_cdt_6() {
         // constexpr explicit operator bool()const
         // Note that I must be explicit about calling the operator because it is defined as `explicit`
-        assert_eq!(false, Name::from(0).into());
-        assert_eq!(true, Name::from(1).into());
+        assert!(!bool::from(Name::from(0)));
+        assert!(bool::from(Name::from(1)));
 
-        assert_eq!(false, Name::from("").into());
-        assert_eq!(true, Name::from("1").into());
+        assert!(!bool::from(Name::from("")));
+        assert!(bool::from(Name::from("1")));
 
-        assert_eq!(true, false == Name::from(0).into());
+        #[allow(clippy::bool_assert_comparison, clippy::bool_comparison)]
+        {
+            assert_eq!(true, false == bool::from(Name::from(0)));
+        }
     }
 
     #[test]
@@ -476,106 +589,106 @@ mod tests {
         assert_eq!(Name::from("aaaaaaaaaaaaj").to_string(), "aaaaaaaaaaaaj");
         assert_eq!(Name::from("zzzzzzzzzzzzj").to_string(), "zzzzzzzzzzzzj");
 
-        assert_eq!(Name::from("1") == Name::from("1"), true);
+        assert_eq!(Name::from("1"), Name::from("1"));
     }
 
     #[test]
     fn test_cdt_9() {
         // friend constexpr bool operator==(const name&, const name&)
-        assert_eq!(Name::from("1") == Name::from("1"), true);
-        assert_eq!(Name::from("5") == Name::from("5"), true);
-        assert_eq!(Name::from("a") == Name::from("a"), true);
-        assert_eq!(Name::from("z") == Name::from("z"), true);
+        assert_eq!(Name::from("1"), Name::from("1"));
+        assert_eq!(Name::from("5"), Name::from("5"));
+        assert_eq!(Name::from("a"), Name::from("a"));
+        assert_eq!(Name::from("z"), Name::from("z"));
 
-        assert_eq!(Name::from("abc") == Name::from("abc"), true);
-        assert_eq!(Name::from("123") == Name::from("123"), true);
+        assert_eq!(Name::from("abc"), Name::from("abc"));
+        assert_eq!(Name::from("123"), Name::from("123"));
 
-        assert_eq!(Name::from(".abc") == Name::from(".abc"), true);
-        assert_eq!(Name::from(".........abc") == Name::from(".........abc"), true);
-        assert_eq!(Name::from("123.") == Name::from("123"), true);
-        assert_eq!(Name::from("123.........") == Name::from("123"), true);
-        assert_eq!(Name::from(".a.b.c.1.2.3.") == Name::from(".a.b.c.1.2.3"), true);
+        assert_eq!(Name::from(".abc"), Name::from(".abc"));
+        assert_eq!(Name::from(".........abc"), Name::from(".........abc"));
+        assert_eq!(Name::from("123."), Name::from("123"));
+        assert_eq!(Name::from("123........."), Name::from("123"));
+        assert_eq!(Name::from(".a.b.c.1.2.3."), Name::from(".a.b.c.1.2.3"));
 
-        assert_eq!(Name::from("abc.123") == Name::from("abc.123"), true);
-        assert_eq!(Name::from("123.abc") == Name::from("123.abc"), true);
+        assert_eq!(Name::from("abc.123"), Name::from("abc.123"));
+        assert_eq!(Name::from("123.abc"), Name::from("123.abc"));
 
-        assert_eq!(Name::from("12345abcdefgj") == Name::from("12345abcdefgj"), true);
-        assert_eq!(Name::from("hijklmnopqrsj") == Name::from("hijklmnopqrsj"), true);
-        assert_eq!(Name::from("tuvwxyz.1234j") == Name::from("tuvwxyz.1234j"), true);
+        assert_eq!(Name::from("12345abcdefgj"), Name::from("12345abcdefgj"));
+        assert_eq!(Name::from("hijklmnopqrsj"), Name::from("hijklmnopqrsj"));
+        assert_eq!(Name::from("tuvwxyz.1234j"), Name::from("tuvwxyz.1234j"));
 
-        assert_eq!(Name::from("111111111111j") == Name::from("111111111111j"), true);
-        assert_eq!(Name::from("555555555555j") == Name::from("555555555555j"), true);
-        assert_eq!(Name::from("aaaaaaaaaaaaj") == Name::from("aaaaaaaaaaaaj"), true);
-        assert_eq!(Name::from("zzzzzzzzzzzzj") == Name::from("zzzzzzzzzzzzj"), true);
+        assert_eq!(Name::from("111111111111j"), Name::from("111111111111j"));
+        assert_eq!(Name::from("555555555555j"), Name::from("555555555555j"));
+        assert_eq!(Name::from("aaaaaaaaaaaaj"), Name::from("aaaaaaaaaaaaj"));
+        assert_eq!(Name::from("zzzzzzzzzzzzj"), Name::from("zzzzzzzzzzzzj"));
 
         // test constexpr
-        assert_eq!(Name::from("1") == Name::from("1"), true);
+        assert_eq!(Name::from("1"), Name::from("1"));
     }
 
     #[test]
     fn test_cdt_10() {
         // friend constexpr bool operator!=(const name&, const name&)
-        assert_eq!(Name::from("1") != Name::new(), true);
-        assert_eq!(Name::from("5") != Name::new(), true);
-        assert_eq!(Name::from("a") != Name::new(), true);
-        assert_eq!(Name::from("z") != Name::new(), true);
+        assert!(Name::from("1") != Name::new());
+        assert!(Name::from("5") != Name::new());
+        assert!(Name::from("a") != Name::new());
+        assert!(Name::from("z") != Name::new());
 
-        assert_eq!(Name::from("abc") != Name::new(), true);
-        assert_eq!(Name::from("123") != Name::new(), true);
+        assert!(Name::from("abc") != Name::new());
+        assert!(Name::from("123") != Name::new());
 
-        assert_eq!(Name::from(".abc") != Name::new(), true);
-        assert_eq!(Name::from(".........abc") != Name::new(), true);
-        assert_eq!(Name::from("123.") != Name::new(), true);
-        assert_eq!(Name::from("123.........") != Name::new(), true);
-        assert_eq!(Name::from(".a.b.c.1.2.3.") != Name::new(), true);
+        assert!(Name::from(".abc") != Name::new());
+        assert!(Name::from(".........abc") != Name::new());
+        assert!(Name::from("123.") != Name::new());
+        assert!(Name::from("123.........") != Name::new());
+        assert!(Name::from(".a.b.c.1.2.3.") != Name::new());
 
-        assert_eq!(Name::from("abc.123") != Name::new(), true);
-        assert_eq!(Name::from("123.abc") != Name::new(), true);
+        assert!(Name::from("abc.123") != Name::new());
+        assert!(Name::from("123.abc") != Name::new());
 
-        assert_eq!(Name::from("12345abcdefgj") != Name::new(), true);
-        assert_eq!(Name::from("hijklmnopqrsj") != Name::new(), true);
-        assert_eq!(Name::from("tuvwxyz.1234j") != Name::new(), true);
+        assert!(Name::from("12345abcdefgj") != Name::new());
+        assert!(Name::from("hijklmnopqrsj") != Name::new());
+        assert!(Name::from("tuvwxyz.1234j") != Name::new());
 
-        assert_eq!(Name::from("111111111111j") != Name::new(), true);
-        assert_eq!(Name::from("555555555555j") != Name::new(), true);
-        assert_eq!(Name::from("aaaaaaaaaaaaj") != Name::new(), true);
-        assert_eq!(Name::from("zzzzzzzzzzzzj") != Name::new(), true);
+        assert!(Name::from("111111111111j") != Name::new());
+        assert!(Name::from("555555555555j") != Name::new());
+        assert!(Name::from("aaaaaaaaaaaaj") != Name::new());
+        assert!(Name::from("zzzzzzzzzzzzj") != Name::new());
 
         // test constexpr
-        assert_eq!(Name::from("1") != Name::from("2"), true);
+        assert!(Name::from("1") != Name::from("2"));
     }
 
     #[test]
     fn test_cdt_11() {
         // friend constexpr bool operator<(const name&, const name&)
-        assert_eq!(Name::new() < Name::from("1"), true);
-        assert_eq!(Name::new() < Name::from("5"), true);
-        assert_eq!(Name::new() < Name::from("a"), true);
-        assert_eq!(Name::new() < Name::from("z"), true);
+        assert!(Name::new() < Name::from("1"));
+        assert!(Name::new() < Name::from("5"));
+        assert!(Name::new() < Name::from("a"));
+        assert!(Name::new() < Name::from("z"));
 
-        assert_eq!(Name::new() < Name::from("abc"), true);
-        assert_eq!(Name::new() < Name::from("123"), true);
+        assert!(Name::new() < Name::from("abc"));
+        assert!(Name::new() < Name::from("123"));
 
-        assert_eq!(Name::new() < Name::from(".abc"), true);
-        assert_eq!(Name::new() < Name::from(".........abc"), true);
-        assert_eq!(Name::new() < Name::from("123."), true);
-        assert_eq!(Name::new() < Name::from("123........."), true);
-        assert_eq!(Name::new() < Name::from(".a.b.c.1.2.3."), true);
+        assert!(Name::new() < Name::from(".abc"));
+        assert!(Name::new() < Name::from(".........abc"));
+        assert!(Name::new() < Name::from("123."));
+        assert!(Name::new() < Name::from("123........."));
+        assert!(Name::new() < Name::from(".a.b.c.1.2.3."));
 
-        assert_eq!(Name::new() < Name::from("abc.123"), true);
-        assert_eq!(Name::new() < Name::from("123.abc"), true);
+        assert!(Name::new() < Name::from("abc.123"));
+        assert!(Name::new() < Name::from("123.abc"));
 
-        assert_eq!(Name::new() < Name::from("12345abcdefgj"), true);
-        assert_eq!(Name::new() < Name::from("hijklmnopqrsj"), true);
-        assert_eq!(Name::new() < Name::from("tuvwxyz.1234j"), true);
+        assert!(Name::new() < Name::from("12345abcdefgj"));
+        assert!(Name::new() < Name::from("hijklmnopqrsj"));
+        assert!(Name::new() < Name::from("tuvwxyz.1234j"));
 
-        assert_eq!(Name::new() < Name::from("111111111111j"), true);
-        assert_eq!(Name::new() < Name::from("555555555555j"), true);
-        assert_eq!(Name::new() < Name::from("aaaaaaaaaaaaj"), true);
-        assert_eq!(Name::new() < Name::from("zzzzzzzzzzzzj"), true);
+        assert!(Name::new() < Name::from("111111111111j"));
+        assert!(Name::new() < Name::from("555555555555j"));
+        assert!(Name::new() < Name::from("aaaaaaaaaaaaj"));
+        assert!(Name::new() < Name::from("zzzzzzzzzzzzj"));
 
         // test constexpr
-        assert_eq!(Name::new() < Name::from("1"), true);
+        assert!(Name::new() < Name::from("1"));
     }
 
     #[test]
@@ -605,6 +718,42 @@ mod tests {
         assert_eq!(Name::char_to_value('`'), None);
     }
 
+    #[test]
+    fn test_u64() {
+        let name = Name::from("eosio.token");
+        assert_eq!(name.as_u64(), 6138663591592764928);
+        assert_eq!(Name::from_u64(6138663591592764928), name);
+        assert_eq!(Name::from_u64(0), Name::new());
+    }
+
+    #[test]
+    fn test_prefix_suffix() {
+        let name = Name::from("eosio.token");
+        assert_eq!(name.prefix(), Name::from("eosio"));
+        assert_eq!(name.suffix(), Name::from("token"));
+
+        let name = Name::from("eosio");
+        assert_eq!(name.prefix(), name);
+        assert_eq!(name.suffix(), name);
+    }
+
+    #[test]
+    fn test_matches_pattern() {
+        assert!(Name::from("eosio.token").matches_pattern("eosio.*"));
+        assert!(Name::from("eosio.token").matches_pattern("*.token"));
+        assert!(Name::from("eosio.token").matches_pattern("eosio.token"));
+        assert!(Name::from("a.b.token").matches_pattern("*.token"));
+        assert!(Name::from("a.b.token").matches_pattern("a.*"));
+        assert!(!Name::from("eosio").matches_pattern("*.token"));
+        assert!(!Name::from("eosio").matches_pattern("eosio.*"));
+        assert!(!Name::from("mytoken").matches_pattern("*.token"));
+        assert!(!Name::from("eosioxtoken").matches_pattern("eosio.*"));
+        assert!(!Name::from("eosio.token").matches_pattern("eosio"));
+        assert!(!Name::from("eosio.token").matches_pattern("*"));
+        assert!(!Name::from("eosio.token").matches_pattern("eos*.token"));
+        assert!(!Name::from("eosio.token").matches_pattern("*.*"));
+    }
+
     #[test]
     fn test_copy() {
         let name = Name::from("aaaaaaaaaaaa");
@@ -615,7 +764,7 @@ mod tests {
     #[test]
     fn test_clone() {
         let name = Name::from("aaaaaaaaaaaa");
-        let cloned_name = name.clone();
+        let cloned_name = Clone::clone(&name);
         assert_eq!(name, cloned_name);
     }
 
@@ -635,7 +784,76 @@ mod tests {
     #[test]
     fn test_from_self() {
         let name = Name::from("aaaaaaaaaaaa");
-        assert_eq!(Name::from(name), name);
+        #[allow(clippy::useless_conversion)]
+        let converted = Name::from(name);
+        assert_eq!(converted, name);
+    }
+
+    const EOSIO: Name = Name::from_u64(6138663577826885632);
+    const EOSIO_TOKEN: Name = Name::from_str_const("eosio.token");
+
+    #[test]
+    fn test_const() {
+        const RAW: u64 = EOSIO.as_u64();
+        assert_eq!(EOSIO, Name::from("eosio"));
+        assert_eq!(RAW, Name::from("eosio").raw());
+        assert_eq!(EOSIO_TOKEN, Name::from("eosio.token"));
+        assert_eq!(Name::new(), Name::from_str_const(""));
+    }
+
+    #[test]
+    fn test_from_str_const() {
+        for s in [
+            "a",
+            "1",
+            "5",
+            "eosio",
+            ".eosio",
+            "eosio.",
+            "a.b.c",
+            "123451234512",
+            "zzzzzzzzzzzzj",
+            ".............",
+        ] {
+            assert_eq!(Name::from_str_const(s), Name::from_str(s).unwrap(), "{}", s);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "name has an invalid character")]
+    fn test_from_str_const_bad_char() {
+        let _ = Name::from_str_const("EOSIO");
+    }
+
+    #[test]
+    #[should_panic(expected = "name has an invalid 13th character")]
+    fn test_from_str_const_bad_13th_char() {
+        let _ = Name::from_str_const("zzzzzzzzzzzzk");
+    }
+
+    #[test]
+    #[should_panic(expected = "name is longer than 13 characters")]
+    fn test_from_str_const_too_long() {
+        let _ = Name::from_str_const("zzzzzzzzzzzzzz");
+    }
+
+    #[test]
+    fn test_pack() {
+        let name = Name::from("eosio.token");
+        assert_eq!(name.to_packed(), [0x00, 0xa6, 0x82, 0x34, 0x03, 0xea, 0x30, 0x55]);
+        assert_eq!(Name::from_packed(&name.to_packed()), Ok(name));
+        assert_eq!(Name::from_packed(&[0; 7]), Err(ParseError::UnexpectedEof));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let name = Name::from("eosio.token");
+        assert_eq!(serde_json::to_string(&name).unwrap(), r#""eosio.token""#);
+        assert_eq!(serde_json::from_str::<Name>(r#""eosio.token""#).unwrap(), name);
+        assert_eq!(serde_json::to_string(&Name::new()).unwrap(), r#""""#);
+        assert!(serde_json::from_str::<Name>(r#""EOSIO""#).is_err());
+        assert!(serde_json::from_str::<Name>("6138663591592764928").is_err());
     }
 
     proptest! {
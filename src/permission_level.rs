@@ -0,0 +1,58 @@
+use crate::{Name, Pack, ParseError, Unpack};
+
+/// The `PermissionLevel` struct represents an authorization of an action, e.g. `alice@active`
+///
+/// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/contracts/eosio/action.hpp>
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Name, PermissionLevel};
+///
+/// let auth = PermissionLevel::new(Name::from("alice"), Name::from("active"));
+/// assert_eq!("alice", auth.actor.to_string());
+/// ```
+#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Default)]
+pub struct PermissionLevel {
+    pub actor: Name,
+    pub permission: Name,
+}
+
+impl PermissionLevel {
+    #[inline]
+    #[must_use]
+    pub fn new(actor: Name, permission: Name) -> Self {
+        PermissionLevel { actor, permission }
+    }
+}
+
+impl Pack for PermissionLevel {
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.actor.pack(out);
+        self.permission.pack(out);
+    }
+}
+
+impl Unpack for PermissionLevel {
+    fn unpack(data: &[u8], pos: &mut usize) -> Result<Self, ParseError> {
+        let actor = Name::unpack(data, pos)?;
+        let permission = Name::unpack(data, pos)?;
+        Ok(PermissionLevel { actor, permission })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack_unpack() {
+        let auth = PermissionLevel::new(Name::from("alice"), Name::from("active"));
+        let packed = auth.to_packed();
+        assert_eq!(packed.len(), 16);
+        assert_eq!(packed[..8], Name::from("alice").value.to_le_bytes());
+        assert_eq!(packed[8..], Name::from("active").value.to_le_bytes());
+        assert_eq!(PermissionLevel::from_packed(&packed), Ok(auth));
+        assert_eq!(PermissionLevel::from_packed(&packed[..12]), Err(ParseError::UnexpectedEof));
+    }
+}
@@ -0,0 +1,14 @@
+//! The most commonly used types, for a glob import
+//!
+//! # Examples
+//!
+//! ```
+//! use antelope::prelude::*;
+//!
+//! let asset = Asset::from_amount(10000, Symbol::from("4,EOS"));
+//! assert_eq!(Ok(asset), "1.0000 EOS".parse::<Asset>());
+//! assert_eq!(SymbolCode::from("EOS"), asset.symbol.code());
+//! assert_eq!(Microseconds::from(1_000_000), TimePoint::from(TimePointSec::from(1)).time_since_epoch());
+//! ```
+
+pub use crate::{Asset, Microseconds, Name, ParseError, Symbol, SymbolCode, TimePoint, TimePointSec};
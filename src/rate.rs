@@ -0,0 +1,76 @@
+use crate::{check, Asset, Microseconds};
+
+/// The `Rate` struct represents an amount accruing linearly over time, e.g. a streaming payment
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Asset, Microseconds, Rate};
+///
+/// let rate = Rate::per_second(Asset::from("1.0000 EOS"));
+/// assert_eq!("2.5000 EOS", rate.accrued(Microseconds::from(2_500_000)).to_string());
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Rate {
+    pub amount: Asset,
+    pub per: Microseconds,
+}
+
+impl Rate {
+    #[inline]
+    #[must_use]
+    pub fn new(amount: Asset, per: Microseconds) -> Self {
+        Rate { amount, per }
+    }
+
+    /// Returns the rate of `amount` per second
+    #[inline]
+    #[must_use]
+    pub fn per_second(amount: Asset) -> Self {
+        Rate::new(amount, Microseconds::from(1_000_000))
+    }
+
+    /// Returns the amount accrued over `duration`, truncated toward zero
+    ///
+    /// The product is computed in 128 bits; panics if `per` is zero or the result is out of range
+    #[must_use]
+    pub fn accrued(&self, duration: Microseconds) -> Asset {
+        check(self.per.count() != 0, "divide by zero");
+        let tmp = (self.amount.amount as i128) * (duration.count() as i128) / (self.per.count() as i128);
+        check(tmp <= Asset::MAX_AMOUNT as i128, "accrual overflow");
+        check(tmp >= Asset::MIN_AMOUNT as i128, "accrual underflow");
+        Asset::from_amount(tmp as i64, self.amount.symbol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Symbol;
+
+    #[test]
+    fn test_accrued() {
+        let rate = Rate::per_second(Asset::from("1.0000 EOS"));
+        assert_eq!(rate.accrued(Microseconds::from(2_500_000)), Asset::from("2.5000 EOS"));
+        assert_eq!(rate.accrued(Microseconds::from(0)), Asset::from("0.0000 EOS"));
+        // 0.00009 EOS is truncated
+        assert_eq!(rate.accrued(Microseconds::from(99)), Asset::from("0.0000 EOS"));
+        assert_eq!(rate.accrued(Microseconds::from(100)), Asset::from("0.0001 EOS"));
+
+        let rate = Rate::new(Asset::from("-3.00 USD"), Microseconds::from(60_000_000));
+        assert_eq!(rate.accrued(Microseconds::from(30_000_000)), Asset::from("-1.50 USD"));
+    }
+
+    #[test]
+    #[should_panic(expected = "accrual overflow")]
+    fn test_accrued_overflow() {
+        let rate = Rate::per_second(Asset::from_amount(Asset::MAX_AMOUNT, Symbol::from("4,EOS")));
+        let _ = rate.accrued(Microseconds::from(2_000_000));
+    }
+
+    #[test]
+    #[should_panic(expected = "divide by zero")]
+    fn test_accrued_zero_period() {
+        let _ = Rate::new(Asset::from("1.0000 EOS"), Microseconds::new()).accrued(Microseconds::from(1));
+    }
+}
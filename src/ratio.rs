@@ -0,0 +1,23 @@
+/// The `Ratio` struct represents a fraction `num / den`, e.g. a fee or a tax rate
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Asset, Ratio};
+///
+/// let fee = Asset::from("100.0000 EOS") * Ratio::new(3, 100);
+/// assert_eq!("3.0000 EOS", fee.to_string());
+/// ```
+#[derive(Eq, Copy, Clone, Debug, PartialEq, Hash)]
+pub struct Ratio {
+    pub num: i64,
+    pub den: i64,
+}
+
+impl Ratio {
+    #[inline]
+    #[must_use]
+    pub const fn new(num: i64, den: i64) -> Self {
+        Ratio { num, den }
+    }
+}
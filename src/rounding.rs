@@ -0,0 +1,88 @@
+/// The `Rounding` enum selects how a result that falls between two representable amounts is rounded
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Asset, Rounding};
+///
+/// let asset = Asset::from("0.0003 SYM");
+/// assert_eq!("0.0001 SYM", (asset / 2).to_string());
+/// assert_eq!("0.0002 SYM", asset.div_rounded(2, Rounding::HalfUp).to_string());
+/// ```
+#[derive(Eq, Copy, Clone, Debug, PartialEq, Hash)]
+pub enum Rounding {
+    /// Truncate, as integer division does
+    TowardZero,
+    /// Round up the magnitude
+    AwayFromZero,
+    /// Round toward negative infinity
+    Floor,
+    /// Round toward positive infinity
+    Ceil,
+    /// Round to the nearest, ties away from zero
+    HalfUp,
+    /// Round to the nearest, ties to the even neighbor (banker's rounding)
+    HalfEven,
+}
+
+impl Rounding {
+    /// Returns `n / d` rounded with this mode
+    pub(crate) fn div(self, n: i128, d: i128) -> i128 {
+        let quotient = n / d;
+        let remainder = n % d;
+        if remainder == 0 {
+            return quotient;
+        }
+        let negative = (n < 0) != (d < 0);
+        // compare twice the remainder against the divisor to find out which half it lies in
+        let half = (2 * remainder.unsigned_abs()).cmp(&d.unsigned_abs());
+        let away = match self {
+            Rounding::TowardZero => false,
+            Rounding::AwayFromZero => true,
+            Rounding::Floor => negative,
+            Rounding::Ceil => !negative,
+            Rounding::HalfUp => half.is_ge(),
+            Rounding::HalfEven => half.is_gt() || (half.is_eq() && quotient % 2 != 0),
+        };
+        match (away, negative) {
+            (false, _) => quotient,
+            (true, false) => quotient + 1,
+            (true, true) => quotient - 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn div_all(n: i128, d: i128) -> [i128; 6] {
+        [
+            Rounding::TowardZero,
+            Rounding::AwayFromZero,
+            Rounding::Floor,
+            Rounding::Ceil,
+            Rounding::HalfUp,
+            Rounding::HalfEven,
+        ]
+        .map(|mode| mode.div(n, d))
+    }
+
+    #[test]
+    fn test_div() {
+        assert_eq!(div_all(6, 3), [2, 2, 2, 2, 2, 2]);
+        assert_eq!(div_all(7, 3), [2, 3, 2, 3, 2, 2]);
+        assert_eq!(div_all(8, 3), [2, 3, 2, 3, 3, 3]);
+        assert_eq!(div_all(-7, 3), [-2, -3, -3, -2, -2, -2]);
+        assert_eq!(div_all(-8, 3), [-2, -3, -3, -2, -3, -3]);
+        assert_eq!(div_all(7, -3), [-2, -3, -3, -2, -2, -2]);
+    }
+
+    #[test]
+    fn test_div_ties() {
+        assert_eq!(div_all(5, 2), [2, 3, 2, 3, 3, 2]);
+        assert_eq!(div_all(7, 2), [3, 4, 3, 4, 4, 4]);
+        assert_eq!(div_all(-5, 2), [-2, -3, -3, -2, -3, -2]);
+        assert_eq!(div_all(1, 2), [0, 1, 0, 1, 1, 0]);
+    }
+}
@@ -0,0 +1,220 @@
+//! Helpers to be used with `#[serde(with = ...)]`, and lenient wrappers such as `AssetLenient`
+//!
+//! # Examples
+//!
+//! ```
+//! use antelope::Symbol;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Row {
+//!     #[serde(with = "antelope::serde::symbol_as_raw")]
+//!     symbol: Symbol,
+//! }
+//! ```
+
+use crate::{Asset, ParseError, Symbol};
+
+/// (De)serializes a `Symbol` as its raw `u64`, e.g. `1397703940` for `4,EOS`, instead of a string
+///
+/// A raw value that is not a valid symbol is rejected on deserialization
+pub mod symbol_as_raw {
+    use ::serde::de::Error;
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::{ParseError, Symbol};
+
+    pub fn serialize<S: Serializer>(symbol: &Symbol, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(symbol.raw())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Symbol, D::Error> {
+        let raw = u64::deserialize(deserializer)?;
+        let symbol = Symbol::from(raw);
+        if !symbol.is_valid() {
+            return Err(D::Error::custom(ParseError::BadSymbol(raw.to_string())));
+        }
+        Ok(symbol)
+    }
+}
+
+/// Deserializes an `Asset` from the shapes found in the wild, for JSON producers that do not
+/// stick to the string form
+///
+/// Accepted shapes:
+///
+/// - the string form, `"1.0000 EOS"`
+/// - an object with the amount in the smallest unit, `{"amount": 10000, "symbol": "4,EOS"}`
+/// - an object with the amount as a decimal string, `{"amount": "1.0000", "symbol": "4,EOS"}`
+///
+/// Rejected as ambiguous: a bare number, an object without `symbol`, a floating point `amount`
+/// (`1.0` could be the value or the smallest unit) and a decimal `amount` whose number of
+/// fractional digits differs from the symbol precision. Other fields of the object are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::serde::AssetLenient;
+/// use antelope::Asset;
+///
+/// let AssetLenient(asset) = serde_json::from_str(r#"{"amount":10000,"symbol":"4,EOS"}"#).unwrap();
+/// assert_eq!(Asset::from("1.0000 EOS"), asset);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AssetLenient(pub Asset);
+
+impl From<AssetLenient> for Asset {
+    #[inline]
+    fn from(lenient: AssetLenient) -> Self {
+        lenient.0
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssetLenient {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AssetLenientVisitor).map(AssetLenient)
+    }
+}
+
+struct AssetLenientVisitor;
+
+impl<'de> ::serde::de::Visitor<'de> for AssetLenientVisitor {
+    type Value = Asset;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("an asset string or an object with amount and symbol")
+    }
+
+    fn visit_str<E: ::serde::de::Error>(self, s: &str) -> Result<Asset, E> {
+        s.parse().map_err(E::custom)
+    }
+
+    fn visit_map<A: ::serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Asset, A::Error> {
+        use ::serde::de::Error;
+
+        let mut amount = None;
+        let mut symbol = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "amount" => amount = Some(map.next_value::<LenientAmount>()?),
+                "symbol" => symbol = Some(map.next_value::<String>()?),
+                _ => {
+                    map.next_value::<::serde::de::IgnoredAny>()?;
+                }
+            }
+        }
+        let amount = amount.ok_or_else(|| A::Error::missing_field("amount"))?;
+        let symbol: Symbol = symbol
+            .ok_or_else(|| A::Error::missing_field("symbol"))?
+            .parse()
+            .map_err(A::Error::custom)?;
+        match amount {
+            LenientAmount::Units(units) => {
+                let asset = Asset::from_amount(units, symbol);
+                if !asset.is_valid() {
+                    return Err(A::Error::custom(ParseError::OutOfRange));
+                }
+                Ok(asset)
+            }
+            LenientAmount::Decimal(decimal) => {
+                Asset::from_str_checked(&format!("{} {}", decimal, symbol.code()), symbol).map_err(A::Error::custom)
+            }
+        }
+    }
+}
+
+/// The `amount` of an object: an integer in the smallest unit, or a decimal string
+enum LenientAmount {
+    Units(i64),
+    Decimal(String),
+}
+
+impl<'de> ::serde::Deserialize<'de> for LenientAmount {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(LenientAmountVisitor)
+    }
+}
+
+struct LenientAmountVisitor;
+
+impl ::serde::de::Visitor<'_> for LenientAmountVisitor {
+    type Value = LenientAmount;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("an integer amount in the smallest unit or a decimal string")
+    }
+
+    fn visit_i64<E: ::serde::de::Error>(self, v: i64) -> Result<LenientAmount, E> {
+        Ok(LenientAmount::Units(v))
+    }
+
+    fn visit_u64<E: ::serde::de::Error>(self, v: u64) -> Result<LenientAmount, E> {
+        i64::try_from(v)
+            .map(LenientAmount::Units)
+            .map_err(|_| E::custom(ParseError::OutOfRange))
+    }
+
+    fn visit_str<E: ::serde::de::Error>(self, v: &str) -> Result<LenientAmount, E> {
+        Ok(LenientAmount::Decimal(v.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+
+    use super::AssetLenient;
+    use crate::{Asset, Symbol};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Row {
+        #[serde(with = "crate::serde::symbol_as_raw")]
+        symbol: Symbol,
+    }
+
+    #[test]
+    fn test_symbol_as_raw() {
+        let row = Row {
+            symbol: Symbol::from("4,EOS"),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"{"symbol":1397703940}"#);
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+    }
+
+    #[test]
+    fn test_symbol_as_raw_invalid() {
+        let err = serde_json::from_str::<Row>(r#"{"symbol":4}"#).unwrap_err();
+        assert!(err.to_string().starts_with("bad symbol: 4"));
+        assert!(serde_json::from_str::<Row>(r#"{"symbol":"4,EOS"}"#).is_err());
+        assert!(serde_json::from_str::<Row>(r#"{"symbol":-1}"#).is_err());
+    }
+
+    fn lenient(json: &str) -> Result<Asset, serde_json::Error> {
+        serde_json::from_str::<AssetLenient>(json).map(Asset::from)
+    }
+
+    #[test]
+    fn test_asset_lenient() {
+        let expected = Asset::from("1.0000 EOS");
+        assert_eq!(lenient(r#""1.0000 EOS""#).unwrap(), expected);
+        assert_eq!(lenient(r#"{"amount":10000,"symbol":"4,EOS"}"#).unwrap(), expected);
+        assert_eq!(lenient(r#"{"symbol":"4,EOS","amount":"1.0000"}"#).unwrap(), expected);
+        assert_eq!(
+            lenient(r#"{"amount":-5,"symbol":"0,SYS","contract":"eosio.token"}"#).unwrap(),
+            Asset::from("-5 SYS")
+        );
+    }
+
+    #[test]
+    fn test_asset_lenient_rejected() {
+        assert!(lenient("10000").is_err());
+        assert!(lenient(r#""1.0000""#).is_err());
+        assert!(lenient(r#"{"amount":10000}"#).is_err());
+        assert!(lenient(r#"{"symbol":"4,EOS"}"#).is_err());
+        assert!(lenient(r#"{"amount":1.0,"symbol":"4,EOS"}"#).is_err());
+        assert!(lenient(r#"{"amount":"1.00","symbol":"4,EOS"}"#).is_err());
+        assert!(lenient(r#"{"amount":10000,"symbol":"EOS"}"#).is_err());
+        assert!(lenient(r#"{"amount":9223372036854775807,"symbol":"4,EOS"}"#).is_err());
+    }
+}
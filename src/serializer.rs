@@ -0,0 +1,219 @@
+use crate::{ParseError, VarUint32};
+
+/// The `Pack` trait serializes a value into the Antelope binary format
+///
+/// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/datastream.hpp>
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Float128, Pack};
+///
+/// let packed = Float128::from(1.0).to_packed();
+/// assert_eq!(16, packed.len());
+/// ```
+pub trait Pack {
+    /// Appends the packed bytes of the value to `out`
+    fn pack(&self, out: &mut Vec<u8>);
+
+    /// Returns the packed bytes of the value
+    fn to_packed(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.pack(&mut out);
+        out
+    }
+}
+
+/// The `Unpack` trait deserializes a value from the Antelope binary format
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Float128, Pack, Unpack};
+///
+/// let packed = Float128::from(1.0).to_packed();
+/// assert_eq!(Ok(Float128::from(1.0)), Float128::from_packed(&packed));
+/// ```
+pub trait Unpack: Sized {
+    /// Reads a value from `data` at `pos`, advancing `pos` past the consumed bytes
+    fn unpack(data: &[u8], pos: &mut usize) -> Result<Self, ParseError>;
+
+    /// Reads a value that spans all of `data`
+    fn from_packed(data: &[u8]) -> Result<Self, ParseError> {
+        let mut pos = 0;
+        let value = Self::unpack(data, &mut pos)?;
+        if pos != data.len() {
+            return Err(ParseError::BadFormat);
+        }
+        Ok(value)
+    }
+}
+
+/// Reads `N` bytes from `data` at `pos`, advancing `pos`
+pub(crate) fn read_bytes<const N: usize>(data: &[u8], pos: &mut usize) -> Result<[u8; N], ParseError> {
+    let end = pos.checked_add(N).ok_or(ParseError::UnexpectedEof)?;
+    let bytes = data.get(*pos..end).ok_or(ParseError::UnexpectedEof)?;
+    *pos = end;
+    Ok(bytes.try_into().expect("slice has exactly N bytes"))
+}
+
+/// Reads `len` bytes from `data` at `pos`, advancing `pos`
+pub(crate) fn read_slice<'a>(data: &'a [u8], pos: &mut usize, len: usize) -> Result<&'a [u8], ParseError> {
+    let end = pos.checked_add(len).ok_or(ParseError::UnexpectedEof)?;
+    let bytes = data.get(*pos..end).ok_or(ParseError::UnexpectedEof)?;
+    *pos = end;
+    Ok(bytes)
+}
+
+macro_rules! impl_pack_int {
+    ($($t:ty),*) => {
+        $(
+            impl Pack for $t {
+                fn pack(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+
+            impl Unpack for $t {
+                fn unpack(data: &[u8], pos: &mut usize) -> Result<Self, ParseError> {
+                    read_bytes(data, pos).map(<$t>::from_le_bytes)
+                }
+            }
+        )*
+    };
+}
+
+// fixed-size integers are packed as little-endian bytes
+impl_pack_int!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl<T: Pack> Pack for Vec<T> {
+    /// Packed as the ABI array form: a `VarUint32` length followed by the elements
+    fn pack(&self, out: &mut Vec<u8>) {
+        VarUint32::from(self.len() as u32).pack(out);
+        for item in self {
+            item.pack(out);
+        }
+    }
+}
+
+impl<T: Unpack> Unpack for Vec<T> {
+    fn unpack(data: &[u8], pos: &mut usize) -> Result<Self, ParseError> {
+        let len = VarUint32::unpack(data, pos)?.value as usize;
+        // don't trust the length for the allocation, every element takes at least a byte
+        let mut items = Vec::with_capacity(len.min(data.len().saturating_sub(*pos)));
+        for _ in 0..len {
+            items.push(T::unpack(data, pos)?);
+        }
+        Ok(items)
+    }
+}
+
+impl<T: Pack> Pack for Option<T> {
+    /// Packed as the ABI `$optional` form: a `0`/`1` flag byte followed by the value if present
+    fn pack(&self, out: &mut Vec<u8>) {
+        match self {
+            Some(value) => {
+                out.push(1);
+                value.pack(out);
+            }
+            None => out.push(0),
+        }
+    }
+}
+
+impl<T: Unpack> Unpack for Option<T> {
+    /// Fails with `BadFormat` if the flag byte is neither `0` nor `1`
+    fn unpack(data: &[u8], pos: &mut usize) -> Result<Self, ParseError> {
+        match u8::unpack(data, pos)? {
+            0 => Ok(None),
+            1 => T::unpack(data, pos).map(Some),
+            _ => Err(ParseError::BadFormat),
+        }
+    }
+}
+
+impl Pack for String {
+    /// Packed as a `VarUint32` byte length followed by the UTF-8 bytes
+    fn pack(&self, out: &mut Vec<u8>) {
+        VarUint32::from(self.len() as u32).pack(out);
+        out.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl Unpack for String {
+    /// Fails with `BadFormat` if the bytes are not valid UTF-8
+    fn unpack(data: &[u8], pos: &mut usize) -> Result<Self, ParseError> {
+        let len = VarUint32::unpack(data, pos)?.value as usize;
+        let bytes = read_slice(data, pos, len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| ParseError::BadFormat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_slice() {
+        let data = [1, 2, 3];
+        let mut pos = 1;
+        assert_eq!(read_slice(&data, &mut pos, 2), Ok(&data[1..]));
+        assert_eq!(pos, 3);
+        assert_eq!(read_slice(&data, &mut pos, 0), Ok(&data[3..]));
+        assert_eq!(read_slice(&data, &mut pos, 1), Err(ParseError::UnexpectedEof));
+    }
+
+    #[test]
+    fn test_pack_vec() {
+        assert_eq!(vec![1_u16, 2].to_packed(), [2, 1, 0, 2, 0]);
+        assert_eq!(Vec::<u64>::new().to_packed(), [0]);
+        assert_eq!(Vec::<u16>::from_packed(&[2, 1, 0, 2, 0]), Ok(vec![1, 2]));
+        assert_eq!(Vec::<u16>::from_packed(&[3, 1, 0, 2, 0]), Err(ParseError::UnexpectedEof));
+        assert_eq!(
+            Vec::<u8>::from_packed(&[0xff, 0xff, 0xff, 0xff, 0x0f]),
+            Err(ParseError::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn test_pack_option() {
+        assert_eq!(Some(0x0102_u16).to_packed(), [1, 2, 1]);
+        assert_eq!(None::<u16>.to_packed(), [0]);
+        assert_eq!(Option::<u16>::from_packed(&[1, 2, 1]), Ok(Some(0x0102)));
+        assert_eq!(Option::<u16>::from_packed(&[0]), Ok(None));
+        assert_eq!(Option::<u16>::from_packed(&[2, 2, 1]), Err(ParseError::BadFormat));
+        assert_eq!(Option::<u16>::from_packed(&[1, 2]), Err(ParseError::UnexpectedEof));
+    }
+
+    #[test]
+    fn test_pack_string() {
+        assert_eq!("hi".to_string().to_packed(), [2, b'h', b'i']);
+        assert_eq!(String::new().to_packed(), [0]);
+        assert_eq!(String::from_packed(&[2, b'h', b'i']), Ok("hi".to_string()));
+        assert_eq!(String::from_packed(&[3, b'h', b'i']), Err(ParseError::UnexpectedEof));
+        assert_eq!(String::from_packed(&[1, 0xff]), Err(ParseError::BadFormat));
+    }
+
+    #[test]
+    fn test_pack_int() {
+        assert_eq!(0x12_u8.to_packed(), [0x12]);
+        assert_eq!(0x1234_u16.to_packed(), [0x34, 0x12]);
+        assert_eq!(0x12345678_u32.to_packed(), [0x78, 0x56, 0x34, 0x12]);
+        assert_eq!((-2_i64).to_packed(), [0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]);
+        assert_eq!(u64::from_packed(&u64::MAX.to_packed()), Ok(u64::MAX));
+        assert_eq!(i16::from_packed(&[0xff, 0x7f]), Ok(i16::MAX));
+        assert_eq!(u32::from_packed(&[1, 2, 3]), Err(ParseError::UnexpectedEof));
+    }
+
+    #[test]
+    fn test_read_bytes() {
+        let data = [1, 2, 3, 4, 5];
+        let mut pos = 0;
+        assert_eq!(read_bytes::<2>(&data, &mut pos), Ok([1, 2]));
+        assert_eq!(pos, 2);
+        assert_eq!(read_bytes::<3>(&data, &mut pos), Ok([3, 4, 5]));
+        assert_eq!(pos, 5);
+        assert_eq!(read_bytes::<1>(&data, &mut pos), Err(ParseError::UnexpectedEof));
+        assert_eq!(pos, 5);
+    }
+}
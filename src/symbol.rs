@@ -1,4 +1,4 @@
-use crate::{ParseError, SymbolCode};
+use crate::{check, Pack, ParseError, SymbolCode, Unpack};
 
 use std::cmp::{Ord, PartialEq, PartialOrd};
 use std::convert::From;
@@ -8,12 +8,29 @@ use std::str::FromStr;
 /// The `Symbol` struct represents a symbol
 ///
 /// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/symbol.hpp>
-#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Default)]
+#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Default, Hash)]
 pub struct Symbol {
     value: u64,
 }
 
 impl Symbol {
+    /// The highest precision of a symbol, the single precision policy of this crate
+    ///
+    /// This follows nodeos, which caps the precision of a token at 18 so that `10^precision`
+    /// fits in an `i64`. The CDT `symbol` takes any `uint8_t` precision up to 255, but such a
+    /// symbol cannot be created on chain. `is_valid`, `from_precision` and `FromStr` reject any
+    /// precision above this value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::Symbol;
+    ///
+    /// assert_eq!(18, Symbol::from("18,FOO").precision());
+    /// assert!("19,FOO".parse::<Symbol>().is_err());
+    /// ```
+    pub const MAX_PRECISION: u8 = 18;
+
     /// Returns the raw value of the symbol
     ///
     /// The raw value is the underlying representation of the symbol
@@ -40,9 +57,43 @@ impl Symbol {
         SymbolCode::from(self.value >> 8)
     }
 
+    /// Returns the symbol code without the precision, as in the string form of an asset
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::Symbol;
+    ///
+    /// let sym = Symbol::from("4,EOS");
+    /// assert_eq!("EOS", sym.code_string());
+    /// assert_eq!("4,EOS", sym.to_string());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn code_string(&self) -> String {
+        self.code().to_string()
+    }
+
+    /// Returns true if both symbols have the same code, whatever their precision
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::Symbol;
+    ///
+    /// assert!(Symbol::from("4,EOS").same_code(&Symbol::from("5,EOS")));
+    /// assert!(!Symbol::from("4,EOS").same_code(&Symbol::from("4,WAX")));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn same_code(&self, other: &Symbol) -> bool {
+        self.code() == other.code()
+    }
+
     /// Returns true if the symbol is valid
     ///
-    /// A symbol code is valid if it is not empty and contains only uppercase letters and has a length of at most 7
+    /// A symbol code is valid if it is not empty and contains only uppercase letters and has a length of at most 7;
+    /// the precision must be at most `MAX_PRECISION`
     ///
     /// # Examples
     ///
@@ -50,12 +101,12 @@ impl Symbol {
     /// use antelope::Symbol;
     ///
     /// let sym = Symbol::from("4,FOO");
-    /// assert_eq!(true, sym.is_valid());
+    /// assert!(sym.is_valid());
     /// ```
     #[inline]
     #[must_use]
     pub fn is_valid(&self) -> bool {
-        self.code().is_valid()
+        self.code().is_valid() && self.precision() <= Symbol::MAX_PRECISION
     }
 
     #[inline]
@@ -64,6 +115,27 @@ impl Symbol {
         self.value as u8
     }
 
+    /// Returns true if `10^precision` fits in an `i64`, i.e. the precision is at most 18
+    ///
+    /// A symbol built from a raw `u64` may still encode a precision above `MAX_PRECISION`;
+    /// `Asset` formats such amounts from their digits rather than with `i64` powers of ten
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::Symbol;
+    ///
+    /// use antelope::SymbolCode;
+    ///
+    /// assert!(Symbol::from("18,FOO").is_precision_representable());
+    /// assert!(!Symbol::from((SymbolCode::from("FOO").raw() << 8) | 19).is_precision_representable());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_precision_representable(&self) -> bool {
+        10_i64.checked_pow(self.precision() as u32).is_some()
+    }
+
     /// Returns a new Symbol
     ///
     /// # Examples
@@ -80,12 +152,135 @@ impl Symbol {
         Self { value: 0 }
     }
 
+    /// Returns a symbol of the given code and precision
+    ///
+    /// # Panics
+    ///
+    /// If `precision` is above `MAX_PRECISION`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::{Symbol, SymbolCode};
+    ///
+    /// assert_eq!(Symbol::from("4,EOS"), Symbol::from_precision(SymbolCode::from("EOS"), 4));
+    /// ```
     #[inline]
     #[must_use]
     pub fn from_precision(symcode: SymbolCode, precision: u8) -> Self {
+        check(precision <= Symbol::MAX_PRECISION, "precision exceeds the maximum of 18");
         let value = (symcode.raw() << 8) | precision as u64;
         Symbol { value }
     }
+
+    /// Returns the `4,EOS` symbol
+    ///
+    /// Commonly used symbols are built at compile time, without parsing a string
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::Symbol;
+    ///
+    /// assert_eq!(Symbol::from("4,EOS"), Symbol::eos());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn eos() -> Self {
+        Symbol::from_code_bytes(b"EOS", 4)
+    }
+
+    /// Returns the `8,WAX` symbol
+    #[inline]
+    #[must_use]
+    pub const fn wax() -> Self {
+        Symbol::from_code_bytes(b"WAX", 8)
+    }
+
+    /// Returns the `4,TLOS` symbol
+    #[inline]
+    #[must_use]
+    pub const fn tlos() -> Self {
+        Symbol::from_code_bytes(b"TLOS", 4)
+    }
+
+    /// Returns the `4,USDT` symbol
+    #[inline]
+    #[must_use]
+    pub const fn usdt() -> Self {
+        Symbol::from_code_bytes(b"USDT", 4)
+    }
+
+    /// Returns the `4,SYS` symbol, the default core symbol of new chains
+    #[inline]
+    #[must_use]
+    pub const fn sys() -> Self {
+        Symbol::from_code_bytes(b"SYS", 4)
+    }
+
+    /// Returns the ABI `symbol` form, e.g. `4,EOS`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::Symbol;
+    ///
+    /// assert_eq!("4,EOS", Symbol::eos().to_abi_string());
+    /// assert_eq!("EOS", Symbol::eos().code().to_abi_string());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_abi_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the ABI `symbol` form as a JSON value, e.g. `"4,EOS"`, for action arguments
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::Symbol;
+    ///
+    /// assert_eq!(serde_json::json!("4,EOS"), Symbol::eos().to_json_value());
+    /// ```
+    #[cfg(feature = "serde_json")]
+    #[must_use]
+    pub fn to_json_value(&self) -> serde_json::Value {
+        serde_json::Value::String(self.to_abi_string())
+    }
+
+    /// Parses the ABI `symbol` form, e.g. `4,EOS`
+    ///
+    /// Unlike `from_str`, a symbol with an empty code is rejected
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::Symbol;
+    ///
+    /// assert_eq!(Ok(Symbol::eos()), Symbol::from_abi_string("4,EOS"));
+    /// assert!(Symbol::from_abi_string("EOS").is_err());
+    /// ```
+    pub fn from_abi_string(s: &str) -> Result<Self, ParseError> {
+        let symbol = s.parse::<Symbol>()?;
+        if !symbol.is_valid() {
+            return Err(ParseError::BadSymbolCode(symbol.code().to_string()));
+        }
+        Ok(symbol)
+    }
+
+    /// Packs a symbol code known to be valid together with its precision
+    const fn from_code_bytes(code: &[u8], precision: u8) -> Self {
+        let mut value: u64 = 0;
+        let mut i = code.len();
+        while i > 0 {
+            i -= 1;
+            value = (value << 8) | code[i] as u64;
+        }
+        Symbol {
+            value: (value << 8) | precision as u64,
+        }
+    }
 }
 
 impl Display for Symbol {
@@ -105,8 +300,8 @@ impl FromStr for Symbol {
             return Err(ParseError::BadFormat);
         }
         let precision = match parts[0].parse::<u8>() {
-            Ok(p) => p,
-            Err(_) => return Err(ParseError::BadPrecision(parts[0].to_string())),
+            Ok(p) if p <= Symbol::MAX_PRECISION => p,
+            _ => return Err(ParseError::BadPrecision(parts[0].to_string())),
         };
         let symcode = match SymbolCode::from_str(parts[1]) {
             Ok(sc) => sc,
@@ -116,9 +311,24 @@ impl FromStr for Symbol {
     }
 }
 
+impl PartialEq<&str> for Symbol {
+    /// Compares the symbol with its string form, a malformed string is never equal
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::Symbol;
+    ///
+    /// assert!(Symbol::from("4,EOS") == "4,EOS");
+    /// assert!(Symbol::from("4,EOS") != "EOS");
+    /// ```
+    fn eq(&self, other: &&str) -> bool {
+        other.parse::<Symbol>().is_ok_and(|other| *self == other)
+    }
+}
+
 impl From<&str> for Symbol {
     #[inline]
-    #[must_use]
     fn from(str: &str) -> Self {
         Self::from_str(str).unwrap_or_else(|e| panic!("failed to parse symbol: {}", e))
     }
@@ -126,7 +336,6 @@ impl From<&str> for Symbol {
 
 impl From<u64> for Symbol {
     #[inline]
-    #[must_use]
     fn from(value: u64) -> Self {
         Symbol { value }
     }
@@ -134,7 +343,6 @@ impl From<u64> for Symbol {
 
 impl From<Symbol> for u64 {
     #[inline]
-    #[must_use]
     fn from(sym: Symbol) -> Self {
         sym.value
     }
@@ -142,7 +350,6 @@ impl From<Symbol> for u64 {
 
 impl AsRef<Symbol> for Symbol {
     #[inline]
-    #[must_use]
     fn as_ref(&self) -> &Symbol {
         self
     }
@@ -150,12 +357,66 @@ impl AsRef<Symbol> for Symbol {
 
 impl From<Symbol> for bool {
     #[inline]
-    #[must_use]
     fn from(sym: Symbol) -> Self {
         sym.raw() != 0
     }
 }
 
+impl Pack for Symbol {
+    /// Packed as the raw `u64`
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.value.pack(out);
+    }
+}
+
+impl Unpack for Symbol {
+    fn unpack(data: &[u8], pos: &mut usize) -> Result<Self, ParseError> {
+        u64::unpack(data, pos).map(Symbol::from)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Symbol {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Symbol".into()
+    }
+
+    /// Describes the string form of a symbol, e.g. "4,EOS"
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": r"^\d+,[A-Z]{1,7}$",
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Symbol {
+    /// Generates only valid symbols, with a precision of at most `MAX_PRECISION`
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let symcode = SymbolCode::arbitrary(u)?;
+        let precision = u.int_in_range(0..=Symbol::MAX_PRECISION)?;
+        Ok(Symbol::from_precision(symcode, precision))
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for Symbol {
+    /// Generates only valid symbols, with a precision of at most `MAX_PRECISION`
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let precision = u8::arbitrary(g) % (Symbol::MAX_PRECISION + 1);
+        Symbol::from_precision(SymbolCode::arbitrary(g), precision)
+    }
+
+    /// Shrinks the precision toward 0 first, then the code
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let (code, precision) = (self.code(), self.precision());
+        let precisions = (0..precision).map(move |p| Symbol::from_precision(code, p));
+        let codes = code.shrink().map(move |c| Symbol::from_precision(c, precision));
+        Box::new(precisions.chain(codes))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,7 +435,7 @@ mod tests {
         assert_eq!(1, Symbol::from(1).raw());
         assert_eq!(u64::MAX, Symbol::from(u64::MAX).raw());
 
-        assert_eq!(0 as u64, Symbol::from(0).into());
+        assert_eq!(0_u64, u64::from(Symbol::from(0)));
     }
 
     #[test]
@@ -202,13 +463,13 @@ mod tests {
     #[test]
     fn test_cdt_4() {
         // bool is_valid()const
-        assert_eq!(true, Symbol::from(16640).is_valid()); // "A", precision: 0
-        assert_eq!(true, Symbol::from(23040).is_valid()); // "Z", precision: 0
-        assert_eq!(true, Symbol::from(4702111234474983680).is_valid()); // "AAAAAAA", precision: 0
-        assert_eq!(true, Symbol::from(6510615555426900480).is_valid()); // "ZZZZZZZ", precision: 0
+        assert!(Symbol::from(16640).is_valid()); // "A", precision: 0
+        assert!(Symbol::from(23040).is_valid()); // "Z", precision: 0
+        assert!(Symbol::from(4702111234474983680).is_valid()); // "AAAAAAA", precision: 0
+        assert!(Symbol::from(6510615555426900480).is_valid()); // "ZZZZZZZ", precision: 0
 
-        assert_eq!(false, Symbol::from(16639).is_valid());
-        assert_eq!(false, Symbol::from(6510615555426900736).is_valid());
+        assert!(!Symbol::from(16639).is_valid());
+        assert!(!Symbol::from(6510615555426900736).is_valid());
     }
 
     #[test]
@@ -224,10 +485,22 @@ mod tests {
         assert_eq!(0, Symbol::from_precision(sc2, 0).precision());
         assert_eq!(0, Symbol::from_precision(sc3, 0).precision());
 
-        assert_eq!(255, Symbol::from_precision(sc0, 255).precision());
-        assert_eq!(255, Symbol::from_precision(sc1, 255).precision());
-        assert_eq!(255, Symbol::from_precision(sc2, 255).precision());
-        assert_eq!(255, Symbol::from_precision(sc3, 255).precision());
+        assert_eq!(
+            Symbol::MAX_PRECISION,
+            Symbol::from_precision(sc0, Symbol::MAX_PRECISION).precision()
+        );
+        assert_eq!(
+            Symbol::MAX_PRECISION,
+            Symbol::from_precision(sc1, Symbol::MAX_PRECISION).precision()
+        );
+        assert_eq!(
+            Symbol::MAX_PRECISION,
+            Symbol::from_precision(sc2, Symbol::MAX_PRECISION).precision()
+        );
+        assert_eq!(
+            Symbol::MAX_PRECISION,
+            Symbol::from_precision(sc3, Symbol::MAX_PRECISION).precision()
+        );
     }
 
     #[test]
@@ -247,11 +520,11 @@ mod tests {
     #[test]
     fn test_cdt_7() {
         // constexpr explicit operator bool()const
-        assert_eq!(false, Symbol::from(0).into());
-        assert_eq!(true, Symbol::from(1).into());
+        assert!(!bool::from(Symbol::from(0)));
+        assert!(bool::from(Symbol::from(1)));
 
-        assert_eq!(false, Symbol::from_precision(SymbolCode::from(""), 0).into());
-        assert_eq!(true, Symbol::from_precision(SymbolCode::from("SYMBOLL"), 0).into());
+        assert!(!bool::from(Symbol::from_precision(SymbolCode::from(""), 0)));
+        assert!(bool::from(Symbol::from_precision(SymbolCode::from("SYMBOLL"), 0)));
     }
 
     #[test]
@@ -262,22 +535,52 @@ mod tests {
         let sc3 = SymbolCode::from("ZZZZZZZ");
 
         // friend constexpr bool operator==(const symbol&, const symbol&)
-        assert_eq!(true, Symbol::from_precision(sc0, 0) == Symbol::from_precision(sc0, 0));
-        assert_eq!(true, Symbol::from_precision(sc1, 0) == Symbol::from_precision(sc1, 0));
-        assert_eq!(true, Symbol::from_precision(sc2, 0) == Symbol::from_precision(sc2, 0));
-        assert_eq!(true, Symbol::from_precision(sc3, 0) == Symbol::from_precision(sc3, 0));
+        assert_eq!(Symbol::from_precision(sc0, 0), Symbol::from_precision(sc0, 0));
+        assert_eq!(Symbol::from_precision(sc1, 0), Symbol::from_precision(sc1, 0));
+        assert_eq!(Symbol::from_precision(sc2, 0), Symbol::from_precision(sc2, 0));
+        assert_eq!(Symbol::from_precision(sc3, 0), Symbol::from_precision(sc3, 0));
 
         // friend constexpr bool operator!=(const symbol&, const symbol&)
-        assert_eq!(true, Symbol::from_precision(sc0, 0) != Symbol::new());
-        assert_eq!(true, Symbol::from_precision(sc1, 0) != Symbol::new());
-        assert_eq!(true, Symbol::from_precision(sc2, 0) != Symbol::new());
-        assert_eq!(true, Symbol::from_precision(sc3, 0) != Symbol::new());
+        assert!(Symbol::from_precision(sc0, 0) != Symbol::new());
+        assert!(Symbol::from_precision(sc1, 0) != Symbol::new());
+        assert!(Symbol::from_precision(sc2, 0) != Symbol::new());
+        assert!(Symbol::from_precision(sc3, 0) != Symbol::new());
 
         // friend constexpr bool operator<(const symbol&, const symbol&)
-        assert_eq!(true, Symbol::new() < Symbol::from_precision(sc0, 0));
-        assert_eq!(true, Symbol::new() < Symbol::from_precision(sc1, 0));
-        assert_eq!(true, Symbol::new() < Symbol::from_precision(sc2, 0));
-        assert_eq!(true, Symbol::new() < Symbol::from_precision(sc3, 0));
+        assert!(Symbol::new() < Symbol::from_precision(sc0, 0));
+        assert!(Symbol::new() < Symbol::from_precision(sc1, 0));
+        assert!(Symbol::new() < Symbol::from_precision(sc2, 0));
+        assert!(Symbol::new() < Symbol::from_precision(sc3, 0));
+    }
+
+    #[test]
+    #[cfg(feature = "serde_json")]
+    fn test_to_json_value() {
+        let value = Symbol::from("4,EOS").to_json_value();
+        assert_eq!(value, serde_json::Value::String("4,EOS".to_string()));
+        assert_eq!(value.to_string(), r#""4,EOS""#);
+    }
+
+    #[test]
+    fn test_max_precision() {
+        let sym = Symbol::from_precision(SymbolCode::from("SYM"), Symbol::MAX_PRECISION);
+        assert!(sym.is_valid());
+        assert_eq!("18,SYM".parse::<Symbol>(), Ok(sym));
+        assert_eq!("19,SYM".parse::<Symbol>(), Err(ParseError::BadPrecision("19".to_string())));
+        assert_eq!("256,SYM".parse::<Symbol>(), Err(ParseError::BadPrecision("256".to_string())));
+
+        // a raw symbol may still encode a precision above the maximum
+        let raw = Symbol::from((SymbolCode::from("SYM").raw() << 8) | 19);
+        assert_eq!(raw.precision(), 19);
+        assert!(!raw.is_valid());
+        assert!(Symbol::from("18,SYM").is_precision_representable());
+        assert!(!raw.is_precision_representable());
+    }
+
+    #[test]
+    #[should_panic(expected = "precision exceeds the maximum of 18")]
+    fn test_from_precision_above_max() {
+        let _ = Symbol::from_precision(SymbolCode::from("SYM"), 19);
     }
 
     #[test]
@@ -286,7 +589,7 @@ mod tests {
         // CDT allows empty symbol code
         assert_eq!(Symbol::from("0,"), Symbol::from_precision(SymbolCode::from(""), 0));
         assert_eq!(Symbol::from("5,SYM").to_string(), "5,SYM");
-        assert_eq!(Symbol::from("50,SYM").to_string(), "50,SYM"); // CDT doesn't check precision, could be > 18
+        assert_eq!(Symbol::from("18,SYM").to_string(), "18,SYM"); // CDT doesn't check precision, nodeos caps it at 18
         assert_eq!(Symbol::from("5,SYM").precision(), 5);
         assert_eq!(Symbol::from("5,SYM").code(), SymbolCode::from("SYM"));
     }
@@ -322,7 +625,9 @@ mod tests {
     #[test]
     fn test_from_self() {
         let sym = Symbol::from("4,ABCDEFG");
-        assert_eq!(Symbol::from(sym), sym);
+        #[allow(clippy::useless_conversion)]
+        let converted = Symbol::from(sym);
+        assert_eq!(converted, sym);
     }
 
     #[test]
@@ -335,9 +640,79 @@ mod tests {
         assert_eq!("A,B".parse::<Symbol>(), Err(ParseError::BadPrecision("A".to_string())));
     }
 
+    #[test]
+    fn test_pack() {
+        let sym = Symbol::from("4,EOS");
+        assert_eq!(sym.to_packed(), [0x04, b'E', b'O', b'S', 0, 0, 0, 0]);
+        assert_eq!(Symbol::from_packed(&sym.to_packed()), Ok(sym));
+    }
+
+    #[test]
+    fn test_abi_string() {
+        for s in ["0,A", "4,EOS", "8,WAX", "18,ABCDEFG"] {
+            let symbol = Symbol::from_abi_string(s).unwrap();
+            assert_eq!(symbol.to_abi_string(), s);
+        }
+        assert_eq!(Symbol::from_abi_string("EOS"), Err(ParseError::BadFormat));
+        assert_eq!(Symbol::from_abi_string("4,"), Err(ParseError::BadSymbolCode("".to_string())));
+        assert_eq!(Symbol::from_abi_string("x,EOS"), Err(ParseError::BadPrecision("x".to_string())));
+    }
+
+    #[test]
+    fn test_eq_str() {
+        assert!(Symbol::from("4,EOS") == "4,EOS");
+        assert!(Symbol::from("4,EOS") != "8,EOS");
+        assert!(Symbol::from("4,EOS") != "4,FOO");
+        assert!(Symbol::from("4,EOS") != "4;EOS");
+        assert!(Symbol::from("4,EOS") != "");
+    }
+
+    #[test]
+    fn test_same_code() {
+        assert!(Symbol::from("4,EOS").same_code(&Symbol::from("5,EOS")));
+        assert!(Symbol::from("4,EOS").same_code(&Symbol::from("4,EOS")));
+        assert!(Symbol::from("0,EOS").same_code(&Symbol::from("18,EOS")));
+        assert!(!Symbol::from("4,EOS").same_code(&Symbol::from("4,WAX")));
+        assert!(!Symbol::from("4,EOS").same_code(&Symbol::from("4,EOSS")));
+    }
+
+    #[test]
+    fn test_code_string() {
+        let sym = Symbol::from("4,EOS");
+        assert_eq!(sym.code_string(), "EOS");
+        assert_eq!(sym.to_string(), "4,EOS");
+        assert_eq!(Symbol::new().code_string(), "");
+        // the string form of an asset ends with the code only
+        assert!(crate::Asset::from_amount(10000, sym)
+            .to_string()
+            .ends_with(&format!(" {}", sym.code_string())));
+    }
+
+    #[test]
+    fn test_common_symbols() {
+        assert_eq!(Symbol::eos().precision(), 4);
+        assert_eq!(Symbol::eos().code().to_string(), "EOS");
+        assert_eq!(Symbol::eos(), Symbol::from("4,EOS"));
+        assert_eq!(Symbol::wax(), Symbol::from("8,WAX"));
+        assert_eq!(Symbol::tlos(), Symbol::from("4,TLOS"));
+        assert_eq!(Symbol::usdt(), Symbol::from("4,USDT"));
+        assert_eq!(Symbol::sys(), Symbol::from("4,SYS"));
+
+        const EOS: Symbol = Symbol::eos();
+        assert!(EOS.is_valid());
+    }
+
+    #[test]
+    #[cfg(feature = "schemars")]
+    fn test_json_schema() {
+        let schema = schemars::schema_for!(Symbol);
+        assert_eq!(schema.get("type").and_then(|v| v.as_str()), Some("string"));
+        assert_eq!(schema.get("pattern").and_then(|v| v.as_str()), Some(r"^\d+,[A-Z]{1,7}$"));
+    }
+
     proptest! {
         #[test]
-        fn random_symbols(precision in 0..100, symcode in "[[A-Z]]{1,7}") {
+        fn random_symbols(precision in 0..=Symbol::MAX_PRECISION, symcode in "[[A-Z]]{1,7}") {
             let sym_str = format!("{},{}", precision, symcode);
             let sym = Symbol::from(sym_str.as_str());
             prop_assert_eq!(sym.to_string(), sym_str);
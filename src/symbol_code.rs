@@ -3,7 +3,7 @@ use std::convert::From;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
-use crate::ParseError;
+use crate::{check, Pack, ParseError, Unpack};
 
 /// The `SymbolCode` struct represents a symbol code
 ///
@@ -21,10 +21,10 @@ use crate::ParseError;
 /// let symcode = SymbolCode::from("FOO");
 /// assert_eq!(5197638, symcode.raw());
 /// assert_eq!(3, symcode.length());
-/// assert_eq!(true, symcode.is_valid());
+/// assert!(symcode.is_valid());
 /// assert_eq!("FOO", symcode.to_string());
 /// ```
-#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Default)]
+#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Default, Hash)]
 pub struct SymbolCode {
     /// The raw value of the symbol code
     ///
@@ -44,6 +44,19 @@ pub struct SymbolCode {
 }
 
 impl SymbolCode {
+    /// The maximum number of characters of a symbol code, as many as fit in the raw value
+    /// alongside the precision of a `Symbol`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::SymbolCode;
+    ///
+    /// assert!("ABCDEFG".parse::<SymbolCode>().is_ok());
+    /// assert!("ABCDEFGH".parse::<SymbolCode>().is_err());
+    /// ```
+    pub const MAX_LENGTH: usize = 7;
+
     /// Returns the raw value of the symbol code
     ///
     /// The raw value is the underlying representation of the symbol code
@@ -84,7 +97,7 @@ impl SymbolCode {
         let mut sym: u64 = self.value;
         let mut len: u32 = 0;
 
-        while sym & 0xFF > 0 && len <= 7 {
+        while sym & 0xFF > 0 && len as usize <= Self::MAX_LENGTH {
             len += 1;
             sym >>= 8;
         }
@@ -101,21 +114,25 @@ impl SymbolCode {
     /// use antelope::SymbolCode;
     ///
     /// let symcode = SymbolCode::from("FOO");
-    /// assert_eq!(true, symcode.is_valid());
+    /// assert!(symcode.is_valid());
     /// ```
     #[inline]
     #[must_use]
     pub fn is_valid(&self) -> bool {
+        // the loop below only looks at the first `MAX_LENGTH` bytes
+        if self.value >> (8 * Self::MAX_LENGTH) != 0 {
+            return false;
+        }
         let mut sym: u64 = self.value;
         let mut i = 0;
-        while i < 7 {
+        while i < Self::MAX_LENGTH {
             let c = sym as u8 as char;
             if !c.is_ascii_uppercase() {
                 return false;
             }
             sym >>= 8;
             if sym == 0 {
-                while i < 7 {
+                while i < Self::MAX_LENGTH {
                     sym >>= 8;
                     if (sym & 0xFF) != 0 {
                         return false;
@@ -128,6 +145,41 @@ impl SymbolCode {
         true
     }
 
+    /// Returns true if the symbol code has no characters, i.e. its raw value is 0
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::SymbolCode;
+    ///
+    /// assert!(SymbolCode::new().is_empty());
+    /// assert!(!SymbolCode::from("EOS").is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.value == 0
+    }
+
+    /// Returns true if the symbol code is empty or all whitespace
+    ///
+    /// The empty code is reserved for the empty symbol of `Asset::new()`, so code paths that
+    /// take user input can reject it; `is_valid` rejects it too, along with any other bad code
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::SymbolCode;
+    ///
+    /// assert!(SymbolCode::new().is_system_reserved());
+    /// assert!(!SymbolCode::from("EOS").is_system_reserved());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_system_reserved(&self) -> bool {
+        self.to_string().trim().is_empty()
+    }
+
     /// Returns a new symbol code
     ///
     /// The new symbol code is empty
@@ -147,6 +199,106 @@ impl SymbolCode {
     pub fn new() -> Self {
         Self { value: 0 }
     }
+
+    /// Returns every symbol code of `n` letters, in lexicographic order, e.g. for exhaustive tests
+    ///
+    /// Limited to `n <= 2` to keep the iteration small; panics otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::SymbolCode;
+    ///
+    /// let mut codes = SymbolCode::all_of_length(2);
+    /// assert_eq!(Some(SymbolCode::from("AA")), codes.next());
+    /// assert_eq!(Some(SymbolCode::from("AB")), codes.next());
+    /// ```
+    pub fn all_of_length(n: usize) -> impl Iterator<Item = SymbolCode> {
+        check(n <= 2, "symbol code length must be at most 2");
+        (0..26_u64.pow(n as u32)).map(move |mut index| {
+            // the first letter is the least significant byte
+            let mut value = 0;
+            for _ in 0..n {
+                value = (value << 8) | (b'A' as u64 + index % 26);
+                index /= 26;
+            }
+            SymbolCode { value }
+        })
+    }
+
+    /// Returns the ABI `symbol_code` form, e.g. `EOS`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::SymbolCode;
+    ///
+    /// assert_eq!("EOS", SymbolCode::from("EOS").to_abi_string());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_abi_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Parses the ABI `symbol_code` form, e.g. `EOS`
+    ///
+    /// Unlike `from_str`, the empty symbol code is rejected
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::SymbolCode;
+    ///
+    /// assert_eq!(Ok(SymbolCode::from("EOS")), SymbolCode::from_abi_string("EOS"));
+    /// assert!(SymbolCode::from_abi_string("4,EOS").is_err());
+    /// ```
+    pub fn from_abi_string(s: &str) -> Result<Self, ParseError> {
+        s.parse::<SymbolCode>()
+            .ok()
+            .filter(SymbolCode::is_valid)
+            .ok_or_else(|| ParseError::BadSymbolCode(s.to_string()))
+    }
+
+    /// Parses a symbol code, converting ASCII lowercase letters to uppercase first
+    ///
+    /// For feeds that send lowercase tickers; `from_str` stays strict so that parsing round-trips.
+    /// Unlike `from_str`, an empty string is rejected
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::SymbolCode;
+    ///
+    /// assert_eq!(Ok(SymbolCode::from("EOS")), SymbolCode::from_str_uppercase("eos"));
+    /// ```
+    pub fn from_str_uppercase(s: &str) -> Result<Self, ParseError> {
+        SymbolCode::from_str(&s.to_ascii_uppercase())
+            .ok()
+            .filter(SymbolCode::is_valid)
+            .ok_or_else(|| ParseError::BadSymbolCode(s.to_string()))
+    }
+
+    /// Parses a comma separated list of symbol codes, e.g. a watch-list like `EOS,USDT,WAX`
+    ///
+    /// Whitespace around each entry is ignored and an empty string is an empty list. The first
+    /// invalid entry is reported as `BadSymbolCode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use antelope::{ParseError, SymbolCode};
+    ///
+    /// let codes = SymbolCode::parse_list("EOS, USDT,WAX").unwrap();
+    /// assert_eq!(vec![SymbolCode::from("EOS"), SymbolCode::from("USDT"), SymbolCode::from("WAX")], codes);
+    /// assert_eq!(Err(ParseError::BadSymbolCode("wax".to_string())), SymbolCode::parse_list("EOS,wax"));
+    /// ```
+    pub fn parse_list(s: &str) -> Result<Vec<Self>, ParseError> {
+        if s.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        s.split(',').map(|entry| SymbolCode::from_abi_string(entry.trim())).collect()
+    }
 }
 
 impl Display for SymbolCode {
@@ -160,7 +312,7 @@ impl Display for SymbolCode {
         let mut v = self.value;
         let mut i = 0;
 
-        while i < 7 {
+        while i < SymbolCode::MAX_LENGTH {
             if v == 0 {
                 break;
             }
@@ -175,7 +327,6 @@ impl Display for SymbolCode {
 
 impl From<&str> for SymbolCode {
     #[inline]
-    #[must_use]
     fn from(str: &str) -> Self {
         Self::from_str(str).unwrap_or_else(|e| panic!("{}", e))
     }
@@ -187,7 +338,7 @@ impl FromStr for SymbolCode {
     #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut value: u64 = 0;
-        if s.len() > 7 {
+        if s.len() > SymbolCode::MAX_LENGTH {
             return Err(ParseError::BadSymbolCode(s.to_string()));
         }
         for c in s.chars().rev() {
@@ -202,9 +353,31 @@ impl FromStr for SymbolCode {
     }
 }
 
+impl TryFrom<&[u8]> for SymbolCode {
+    type Error = ParseError;
+
+    /// Builds a symbol code from its raw bytes, e.g. as found in a decoder, without going through `&str`
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() > SymbolCode::MAX_LENGTH || !bytes.iter().all(u8::is_ascii_uppercase) {
+            return Err(ParseError::BadSymbolCode(String::from_utf8_lossy(bytes).into_owned()));
+        }
+        let value = bytes.iter().rev().fold(0u64, |value, &b| (value << 8) | b as u64);
+        Ok(SymbolCode { value })
+    }
+}
+
+impl<const N: usize> TryFrom<[u8; N]> for SymbolCode {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(bytes: [u8; N]) -> Result<Self, Self::Error> {
+        SymbolCode::try_from(&bytes[..])
+    }
+}
+
 impl From<u64> for SymbolCode {
     #[inline]
-    #[must_use]
     fn from(value: u64) -> Self {
         SymbolCode { value }
     }
@@ -212,7 +385,6 @@ impl From<u64> for SymbolCode {
 
 impl From<SymbolCode> for u64 {
     #[inline]
-    #[must_use]
     fn from(symcode: SymbolCode) -> Self {
         symcode.value
     }
@@ -220,7 +392,6 @@ impl From<SymbolCode> for u64 {
 
 impl AsRef<SymbolCode> for SymbolCode {
     #[inline]
-    #[must_use]
     fn as_ref(&self) -> &SymbolCode {
         self
     }
@@ -228,12 +399,70 @@ impl AsRef<SymbolCode> for SymbolCode {
 
 impl From<SymbolCode> for bool {
     #[inline]
-    #[must_use]
     fn from(symcode: SymbolCode) -> Self {
         symcode.raw() != 0
     }
 }
 
+impl Pack for SymbolCode {
+    /// Packed as the raw `u64`
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.value.pack(out);
+    }
+}
+
+impl Unpack for SymbolCode {
+    fn unpack(data: &[u8], pos: &mut usize) -> Result<Self, ParseError> {
+        u64::unpack(data, pos).map(SymbolCode::from)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for SymbolCode {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "SymbolCode".into()
+    }
+
+    /// Describes the string form of a symbol code, e.g. "EOS"
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": "^[A-Z]{1,7}$",
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SymbolCode {
+    /// Generates only valid symbol codes: 1 to 7 uppercase letters
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(1..=SymbolCode::MAX_LENGTH)?;
+        let mut value: u64 = 0;
+        for _ in 0..len {
+            value = (value << 8) | u.int_in_range(b'A'..=b'Z')? as u64;
+        }
+        Ok(SymbolCode { value })
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for SymbolCode {
+    /// Generates only valid symbol codes: 1 to 7 uppercase letters
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let len = usize::arbitrary(g) % SymbolCode::MAX_LENGTH + 1;
+        let code: String = (0..len)
+            .map(|_| *g.choose(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ").unwrap() as char)
+            .collect();
+        SymbolCode::from(code.as_str())
+    }
+
+    /// Shrinks toward the shortest prefix, e.g. "EOS" to "E" and "EO"
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let code = self.to_string();
+        Box::new((1..code.len()).map(move |len| SymbolCode::from(&code[..len])))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -244,7 +473,7 @@ mod tests {
         //// constexpr symbol_code()
         // constexpr uint64_t raw()const
         assert_eq!(0, SymbolCode::new().raw());
-        assert_eq!(0 as u64, SymbolCode::new().into());
+        assert_eq!(0_u64, u64::from(SymbolCode::new()));
     }
 
     #[test]
@@ -267,13 +496,13 @@ mod tests {
     #[test]
     fn test_cdt_4() {
         //// constexpr bool is_valid()const
-        assert_eq!(true, SymbolCode::from(65).is_valid()); // "A"
-        assert_eq!(true, SymbolCode::from(90).is_valid()); // "Z"
-        assert_eq!(true, SymbolCode::from(18367622009667905).is_valid()); // "AAAAAAA"
-        assert_eq!(true, SymbolCode::from(25432092013386330).is_valid()); // "ZZZZZZZ"
+        assert!(SymbolCode::from(65).is_valid()); // "A"
+        assert!(SymbolCode::from(90).is_valid()); // "Z"
+        assert!(SymbolCode::from(18367622009667905).is_valid()); // "AAAAAAA"
+        assert!(SymbolCode::from(25432092013386330).is_valid()); // "ZZZZZZZ"
 
-        assert_eq!(false, SymbolCode::from(64).is_valid());
-        assert_eq!(false, SymbolCode::from(25432092013386331).is_valid());
+        assert!(!SymbolCode::from(64).is_valid());
+        assert!(!SymbolCode::from(25432092013386331).is_valid());
     }
 
     #[test]
@@ -292,11 +521,11 @@ mod tests {
     #[test]
     fn test_cdt_6() {
         // constexpr explicit operator bool()const
-        assert_eq!(false, SymbolCode::from(0).into());
-        assert_eq!(true, SymbolCode::from(1).into());
+        assert!(!bool::from(SymbolCode::from(0)));
+        assert!(bool::from(SymbolCode::from(1)));
 
-        assert_eq!(false, SymbolCode::from("").into());
-        assert_eq!(true, SymbolCode::from("SYMBOL").into());
+        assert!(!bool::from(SymbolCode::from("")));
+        assert!(bool::from(SymbolCode::from("SYMBOL")));
     }
 
     #[test]
@@ -311,28 +540,28 @@ mod tests {
     #[test]
     fn test_cdt_8() {
         // friend bool operator==(const symbol_code&, const symbol_code&)
-        assert_eq!(true, SymbolCode::from("A") == SymbolCode::from("A"));
-        assert_eq!(true, SymbolCode::from("Z") == SymbolCode::from("Z"));
-        assert_eq!(true, SymbolCode::from("AAAAAAA") == SymbolCode::from("AAAAAAA"));
-        assert_eq!(true, SymbolCode::from("ZZZZZZZ") == SymbolCode::from("ZZZZZZZ"));
+        assert_eq!(SymbolCode::from("A"), SymbolCode::from("A"));
+        assert_eq!(SymbolCode::from("Z"), SymbolCode::from("Z"));
+        assert_eq!(SymbolCode::from("AAAAAAA"), SymbolCode::from("AAAAAAA"));
+        assert_eq!(SymbolCode::from("ZZZZZZZ"), SymbolCode::from("ZZZZZZZ"));
     }
 
     #[test]
     fn test_cdt_9() {
         // friend bool operator!=(const symbol_code&, const symbol_code&)
-        assert_eq!(true, SymbolCode::from("A") != SymbolCode::new());
-        assert_eq!(true, SymbolCode::from("Z") != SymbolCode::new());
-        assert_eq!(true, SymbolCode::from("AAAAAAA") != SymbolCode::new());
-        assert_eq!(true, SymbolCode::from("ZZZZZZZ") != SymbolCode::new());
+        assert!(SymbolCode::from("A") != SymbolCode::new());
+        assert!(SymbolCode::from("Z") != SymbolCode::new());
+        assert!(SymbolCode::from("AAAAAAA") != SymbolCode::new());
+        assert!(SymbolCode::from("ZZZZZZZ") != SymbolCode::new());
     }
 
     #[test]
     fn test_cdt_10() {
         // friend bool operator<(const symbol_code&, const symbol_code&)
-        assert_eq!(true, SymbolCode::new() < SymbolCode::from("A"));
-        assert_eq!(true, SymbolCode::new() < SymbolCode::from("Z"));
-        assert_eq!(true, SymbolCode::new() < SymbolCode::from("AAAAAAA"));
-        assert_eq!(true, SymbolCode::new() < SymbolCode::from("ZZZZZZZ"));
+        assert!(SymbolCode::new() < SymbolCode::from("A"));
+        assert!(SymbolCode::new() < SymbolCode::from("Z"));
+        assert!(SymbolCode::new() < SymbolCode::from("AAAAAAA"));
+        assert!(SymbolCode::new() < SymbolCode::from("ZZZZZZZ"));
     }
 
     #[test]
@@ -375,16 +604,19 @@ mod tests {
 
     #[test]
     fn test_partial_eq() {
-        assert_eq!(true, SymbolCode::from(5197638) == SymbolCode::from(5197638));
-        assert_eq!(true, SymbolCode::from(0) != SymbolCode::from(5197638));
-        assert_eq!(false, SymbolCode::from(0) == SymbolCode::from(5197638));
-        assert_eq!(true, SymbolCode::from(5197637) != SymbolCode::from(5197638));
+        assert_eq!(SymbolCode::from(5197638), SymbolCode::from(5197638));
+        assert!(SymbolCode::from(0) != SymbolCode::from(5197638));
+        #[allow(clippy::bool_assert_comparison)]
+        {
+            assert_eq!(false, SymbolCode::from(0) == SymbolCode::from(5197638));
+        }
+        assert!(SymbolCode::from(5197637) != SymbolCode::from(5197638));
     }
 
     #[test]
     fn test_partial_cmp() {
-        assert_eq!(true, SymbolCode::from(0) < SymbolCode::from(1));
-        assert_eq!(false, SymbolCode::from(3) < SymbolCode::from(2));
+        assert!(SymbolCode::from(0) < SymbolCode::from(1));
+        assert!(SymbolCode::from(3) >= SymbolCode::from(2));
     }
 
     #[test]
@@ -415,11 +647,11 @@ mod tests {
 
     #[test]
     fn test_is_valid() {
-        assert_eq!(false, SymbolCode::new().is_valid());
-        assert_eq!(false, SymbolCode::from(0).is_valid());
-        assert_eq!(false, SymbolCode::from(std::u64::MAX).is_valid());
-        assert_eq!(true, SymbolCode::from(5197638).is_valid());
-        assert_eq!(true, SymbolCode::from("FOO").is_valid());
+        assert!(!SymbolCode::new().is_valid());
+        assert!(!SymbolCode::from(0).is_valid());
+        assert!(!SymbolCode::from(u64::MAX).is_valid());
+        assert!(SymbolCode::from(5197638).is_valid());
+        assert!(SymbolCode::from("FOO").is_valid());
     }
 
     #[test]
@@ -471,15 +703,17 @@ mod tests {
     #[test]
     fn test_clone() {
         let symbol_code = SymbolCode::from("ABCDEFG");
-        let cloned_symbol_code = symbol_code.clone();
+        let cloned_symbol_code = Clone::clone(&symbol_code);
         assert_eq!(symbol_code, cloned_symbol_code);
-        assert_eq!(5197638, SymbolCode::from("FOO").clone().value);
+        assert_eq!(5197638, SymbolCode::from("FOO").value);
     }
 
     #[test]
     fn test_from_self() {
         let symcode = SymbolCode::from("ABCDEFG");
-        assert_eq!(SymbolCode::from(symcode), symcode);
+        #[allow(clippy::useless_conversion)]
+        let converted = SymbolCode::from(symcode);
+        assert_eq!(converted, symcode);
     }
 
     #[test]
@@ -494,11 +728,150 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_from_bytes() {
+        assert_eq!(SymbolCode::try_from(&b"EOS"[..]), Ok(SymbolCode::from("EOS")));
+        assert_eq!(SymbolCode::try_from(*b"USDT"), Ok(SymbolCode::from("USDT")));
+        assert_eq!(SymbolCode::try_from(&b""[..]), Ok(SymbolCode::new()));
+        assert_eq!(SymbolCode::try_from(&b"eos"[..]), Err(ParseError::BadSymbolCode("eos".to_string())));
+        assert_eq!(
+            SymbolCode::try_from(&b"ABCDEFGH"[..]),
+            Err(ParseError::BadSymbolCode("ABCDEFGH".to_string()))
+        );
+        assert!(SymbolCode::try_from([0xffu8, b'A']).is_err());
+    }
+
+    #[test]
+    fn test_max_length() {
+        let seven = "ABCDEFG";
+        let eight = "ABCDEFGH";
+        assert_eq!(SymbolCode::from(seven).length() as usize, SymbolCode::MAX_LENGTH);
+        assert!(SymbolCode::from_str(seven).is_ok());
+        assert!(SymbolCode::from_str(eight).is_err());
+        assert!(SymbolCode::from_abi_string(seven).is_ok());
+        assert!(SymbolCode::from_abi_string(eight).is_err());
+        assert!(SymbolCode::from_str_uppercase("abcdefg").is_ok());
+        assert!(SymbolCode::from_str_uppercase("abcdefgh").is_err());
+        assert!(SymbolCode::try_from(seven.as_bytes()).is_ok());
+        assert!(SymbolCode::try_from(eight.as_bytes()).is_err());
+        assert!(SymbolCode::try_from(*b"ABCDEFG").is_ok());
+        assert!(SymbolCode::try_from(*b"ABCDEFGH").is_err());
+        assert!(SymbolCode::parse_list("EOS,ABCDEFGH").is_err());
+        // a raw value can hold 8 characters, which is not a valid code
+        assert!(!SymbolCode::from(u64::from_le_bytes(*b"ABCDEFGH")).is_valid());
+    }
+
+    #[test]
+    fn test_size() {
+        fn assert_copy<T: Copy>() {}
+        assert_copy::<SymbolCode>();
+        assert!(std::mem::size_of::<SymbolCode>() <= 8);
+    }
+
+    #[test]
+    fn test_is_empty() {
+        assert!(SymbolCode::new().is_empty());
+        assert!(SymbolCode::from("").is_empty());
+        assert!(!SymbolCode::from("A").is_empty());
+    }
+
+    #[test]
+    fn test_all_of_length() {
+        assert_eq!(SymbolCode::all_of_length(0).collect::<Vec<_>>(), [SymbolCode::new()]);
+        let codes: Vec<_> = SymbolCode::all_of_length(1).collect();
+        assert_eq!(codes.len(), 26);
+        assert_eq!(codes[0], SymbolCode::from("A"));
+        assert_eq!(codes[25], SymbolCode::from("Z"));
+        let codes: Vec<_> = SymbolCode::all_of_length(2).collect();
+        assert_eq!(codes.len(), 676);
+        assert_eq!(codes[27], SymbolCode::from("BB"));
+        assert!(codes.windows(2).all(|w| w[0].to_string() < w[1].to_string()));
+        assert!(codes.iter().all(SymbolCode::is_valid));
+    }
+
+    #[test]
+    #[should_panic(expected = "symbol code length must be at most 2")]
+    fn test_all_of_length_panic() {
+        let _ = SymbolCode::all_of_length(3);
+    }
+
     #[test]
     fn test_to_bool() {
-        assert_eq!(true, SymbolCode::from("ABCDEFG").into());
-        assert_eq!(false, SymbolCode::default().into());
-        assert_eq!(false, SymbolCode::from("").into());
+        assert!(bool::from(SymbolCode::from("ABCDEFG")));
+        assert!(!bool::from(SymbolCode::default()));
+        assert!(!bool::from(SymbolCode::from("")));
+    }
+
+    #[test]
+    #[cfg(feature = "schemars")]
+    fn test_json_schema() {
+        let schema = schemars::schema_for!(SymbolCode);
+        assert_eq!(schema.get("type").and_then(|v| v.as_str()), Some("string"));
+        assert_eq!(schema.get("pattern").and_then(|v| v.as_str()), Some("^[A-Z]{1,7}$"));
+    }
+
+    #[test]
+    fn test_from_str_uppercase() {
+        assert_eq!(SymbolCode::from_str_uppercase("eos"), Ok(SymbolCode::from("EOS")));
+        assert_eq!(SymbolCode::from_str_uppercase("EoS"), Ok(SymbolCode::from("EOS")));
+        assert_eq!(SymbolCode::from_str_uppercase("EOS"), Ok(SymbolCode::from("EOS")));
+        assert_eq!(
+            SymbolCode::from_str_uppercase("eos1"),
+            Err(ParseError::BadSymbolCode("eos1".to_string()))
+        );
+        assert_eq!(
+            SymbolCode::from_str_uppercase("abcdefgh"),
+            Err(ParseError::BadSymbolCode("abcdefgh".to_string()))
+        );
+        assert_eq!(SymbolCode::from_str_uppercase(""), Err(ParseError::BadSymbolCode("".to_string())));
+        assert_eq!("eos".parse::<SymbolCode>(), Err(ParseError::BadSymbolCode("eos".to_string())));
+    }
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(
+            SymbolCode::parse_list("EOS,USDT,WAX"),
+            Ok(vec![SymbolCode::from("EOS"), SymbolCode::from("USDT"), SymbolCode::from("WAX")])
+        );
+        assert_eq!(SymbolCode::parse_list(" EOS "), Ok(vec![SymbolCode::from("EOS")]));
+        assert_eq!(SymbolCode::parse_list(""), Ok(vec![]));
+        assert_eq!(
+            SymbolCode::parse_list("EOS,TOOLONGCODE,WAX"),
+            Err(ParseError::BadSymbolCode("TOOLONGCODE".to_string()))
+        );
+        assert_eq!(SymbolCode::parse_list("EOS,,WAX"), Err(ParseError::BadSymbolCode("".to_string())));
+    }
+
+    #[test]
+    fn test_pack() {
+        let symcode = SymbolCode::from("EOS");
+        assert_eq!(symcode.to_packed(), [b'E', b'O', b'S', 0, 0, 0, 0, 0]);
+        assert_eq!(SymbolCode::from_packed(&symcode.to_packed()), Ok(symcode));
+    }
+
+    #[test]
+    fn test_abi_string() {
+        for s in ["A", "EOS", "ABCDEFG"] {
+            let symcode = SymbolCode::from_abi_string(s).unwrap();
+            assert_eq!(symcode.to_abi_string(), s);
+        }
+        assert_eq!(SymbolCode::from_abi_string(""), Err(ParseError::BadSymbolCode("".to_string())));
+        assert_eq!(
+            SymbolCode::from_abi_string("4,EOS"),
+            Err(ParseError::BadSymbolCode("4,EOS".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_is_system_reserved() {
+        assert!(SymbolCode::new().is_system_reserved());
+        assert!(SymbolCode::from("").is_system_reserved());
+        assert!(SymbolCode::from(0x2020).is_system_reserved());
+        assert!(crate::Asset::new().symbol.code().is_system_reserved());
+        assert!(!SymbolCode::from("EOS").is_system_reserved());
+        assert!(!SymbolCode::from("A").is_system_reserved());
+        // invalid but not reserved
+        assert!(!SymbolCode::from(0x20_41).is_system_reserved());
     }
 
     proptest! {
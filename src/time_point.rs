@@ -29,7 +29,6 @@ impl TimePoint {
 
 impl From<Microseconds> for TimePoint {
     #[inline]
-    #[must_use]
     fn from(elapsed: Microseconds) -> Self {
         TimePoint { elapsed }
     }
@@ -43,7 +42,6 @@ impl From<TimePointSec> for TimePoint {
 
 impl AsRef<TimePoint> for TimePoint {
     #[inline]
-    #[must_use]
     fn as_ref(&self) -> &TimePoint {
         self
     }
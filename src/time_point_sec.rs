@@ -57,7 +57,7 @@ impl std::fmt::Display for TimePointSec {
      */
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let dt = OffsetDateTime::from_unix_timestamp(self.sec_since_epoch() as i64).expect("format failed");
-        let format = format_description::parse("[year]-[month]-[day]T[hour]:[minute]:[second]").unwrap();
+        let format = format_description::parse_borrowed::<2>("[year]-[month]-[day]T[hour]:[minute]:[second]").unwrap();
         write!(f, "{}", dt.format(&format).unwrap())
     }
 }
@@ -194,8 +194,8 @@ mod tests {
         let time_point2 = TimePointSec { utc_seconds: 123 };
         let time_point3 = TimePointSec { utc_seconds: 456 };
 
-        assert_eq!(time_point1.eq(&time_point2), true);
-        assert_eq!(time_point1.eq(&time_point3), false);
+        assert!(time_point1.eq(&time_point2));
+        assert!(!time_point1.eq(&time_point3));
     }
 
     #[test]
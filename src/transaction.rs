@@ -0,0 +1,116 @@
+use crate::{Action, Pack, TimePointSec, VarUint32};
+
+#[cfg(feature = "crypto")]
+use crate::Checksum256;
+
+/// The `Transaction` struct represents an unsigned transaction
+///
+/// Reference: <https://github.com/AntelopeIO/spring/blob/main/libraries/chain/include/eosio/chain/transaction.hpp>
+///
+/// Each extension is a pair of its type and its packed data
+#[derive(Eq, Clone, Debug, PartialEq, Default)]
+pub struct Transaction {
+    pub expiration: TimePointSec,
+    pub ref_block_num: u16,
+    pub ref_block_prefix: u32,
+    pub max_net_usage_words: VarUint32,
+    pub max_cpu_usage_ms: u8,
+    pub delay_sec: VarUint32,
+    pub context_free_actions: Vec<Action>,
+    pub actions: Vec<Action>,
+    pub transaction_extensions: Vec<(u16, Vec<u8>)>,
+}
+
+impl Transaction {
+    /// Returns the signing digest of the transaction on the given chain
+    ///
+    /// The digest is `sha256(chain_id || packed_trx || context_free_data_hash)`, which is what
+    /// wallets sign. A transaction carries no context-free data, so its hash is 32 zero bytes.
+    #[cfg(feature = "crypto")]
+    #[must_use]
+    pub fn id(&self, chain_id: &Checksum256) -> Checksum256 {
+        let mut data = chain_id.to_packed();
+        self.pack(&mut data);
+        data.extend_from_slice(&[0; 32]);
+        Checksum256::hash(&data)
+    }
+}
+
+impl Pack for Transaction {
+    fn pack(&self, out: &mut Vec<u8>) {
+        self.expiration.utc_seconds.pack(out);
+        self.ref_block_num.pack(out);
+        self.ref_block_prefix.pack(out);
+        self.max_net_usage_words.pack(out);
+        self.max_cpu_usage_ms.pack(out);
+        self.delay_sec.pack(out);
+        self.context_free_actions.pack(out);
+        self.actions.pack(out);
+        VarUint32::from(self.transaction_extensions.len() as u32).pack(out);
+        for (kind, data) in &self.transaction_extensions {
+            kind.pack(out);
+            data.pack(out);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Name, PermissionLevel};
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn transfer_transaction() -> Transaction {
+        Transaction {
+            expiration: TimePointSec::from_iso_string("1998-06-15T08:13:12"),
+            ref_block_num: 1234,
+            ref_block_prefix: 0xaabbccdd,
+            actions: vec![Action {
+                account: Name::from("eosio.token"),
+                name: Name::from("transfer"),
+                authorization: vec![PermissionLevel {
+                    actor: Name::from("alice"),
+                    permission: Name::from("active"),
+                }],
+                // alice -> bob, "1.0000 EOS", memo "hi"
+                data: from_hex("0000000000855c340000000000000e3d102700000000000004454f5300000000026869"),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_pack() {
+        assert_eq!(
+            transfer_transaction().to_packed(),
+            from_hex(concat!(
+                "98d78435d204ddccbbaa000000000100a6823403ea3055000000572d3ccdcd010000000000855c34",
+                "00000000a8ed3232230000000000855c340000000000000e3d102700000000000004454f530000",
+                "000002686900"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_pack_empty() {
+        assert_eq!(Transaction::default().to_packed(), [0; 16]);
+    }
+
+    #[test]
+    #[cfg(feature = "crypto")]
+    fn test_id() {
+        let chain_id = "aca376f206b8fc25a6ed44dbdc66547c36c6c33e3a119ffbeaef943642f0e906"
+            .parse::<Checksum256>()
+            .unwrap();
+        assert_eq!(
+            transfer_transaction().id(&chain_id).to_string(),
+            "8a885ebb85b3b67a4787298c075365b1cc317ac087b31486fa0d5ae80613350f"
+        );
+    }
+}
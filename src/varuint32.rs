@@ -0,0 +1,97 @@
+use crate::{Pack, ParseError, Unpack};
+
+/// The `VarUint32` struct represents the ABI `varuint32` type
+///
+/// Reference: <https://github.com/AntelopeIO/cdt/blob/main/libraries/eosiolib/core/eosio/varint.hpp>
+///
+/// Packed as LEB128: 7 bits per byte, least significant group first, with the high bit set on
+/// every byte but the last. Used for lengths of arrays, strings and bytes.
+///
+/// # Examples
+///
+/// ```
+/// use antelope::{Pack, VarUint32};
+///
+/// assert_eq!(vec![0xac, 0x02], VarUint32::from(300).to_packed());
+/// ```
+#[derive(Eq, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Default, Hash)]
+pub struct VarUint32 {
+    pub value: u32,
+}
+
+impl From<u32> for VarUint32 {
+    #[inline]
+    fn from(value: u32) -> Self {
+        VarUint32 { value }
+    }
+}
+
+impl From<VarUint32> for u32 {
+    #[inline]
+    fn from(value: VarUint32) -> Self {
+        value.value
+    }
+}
+
+impl Pack for VarUint32 {
+    fn pack(&self, out: &mut Vec<u8>) {
+        let mut value = self.value;
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                out.push(byte);
+                return;
+            }
+            out.push(byte | 0x80);
+        }
+    }
+}
+
+impl Unpack for VarUint32 {
+    /// Fails with `BadFormat` if the encoding doesn't fit into 32 bits
+    fn unpack(data: &[u8], pos: &mut usize) -> Result<Self, ParseError> {
+        let mut value: u64 = 0;
+        let mut shift = 0;
+        loop {
+            let byte = u8::unpack(data, pos)?;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+            if shift >= 35 {
+                return Err(ParseError::BadFormat);
+            }
+        }
+        u32::try_from(value).map(VarUint32::from).map_err(|_| ParseError::BadFormat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack() {
+        assert_eq!(VarUint32::from(0).to_packed(), [0x00]);
+        assert_eq!(VarUint32::from(127).to_packed(), [0x7f]);
+        assert_eq!(VarUint32::from(128).to_packed(), [0x80, 0x01]);
+        assert_eq!(VarUint32::from(300).to_packed(), [0xac, 0x02]);
+        assert_eq!(VarUint32::from(u32::MAX).to_packed(), [0xff, 0xff, 0xff, 0xff, 0x0f]);
+    }
+
+    #[test]
+    fn test_unpack() {
+        for value in [0, 1, 127, 128, 300, 16384, u32::MAX] {
+            let packed = VarUint32::from(value).to_packed();
+            assert_eq!(VarUint32::from_packed(&packed), Ok(VarUint32::from(value)));
+        }
+        assert_eq!(VarUint32::from_packed(&[0x80]), Err(ParseError::UnexpectedEof));
+        assert_eq!(VarUint32::from_packed(&[0xff, 0xff, 0xff, 0xff, 0x1f]), Err(ParseError::BadFormat));
+        assert_eq!(
+            VarUint32::from_packed(&[0xff, 0xff, 0xff, 0xff, 0xff, 0x01]),
+            Err(ParseError::BadFormat)
+        );
+    }
+}
@@ -0,0 +1,55 @@
+//! Parse and display round-trips of mainnet token assets, each at the precision of its symbol.
+//!
+//! The data is inline so the test does not depend on the network.
+
+use antelope::{Asset, ParseError};
+
+const ASSETS: &[&str] = &[
+    "1.0000 EOS",
+    "0.0001 EOS",
+    "0.0000 EOS",
+    "10.0000 EOS",
+    "1234567.8901 EOS",
+    "-0.5000 EOS",
+    "-125.0000 EOS",
+    "1.00000000 WAX",
+    "0.00000001 WAX",
+    "-12.34567890 WAX",
+    "386.17259413 WAX",
+    "100.0000 TLOS",
+    "0.0150 TLOS",
+    "25.0000 USDT",
+    "0.0100 USDT",
+    "1.00000000 PBTC",
+    "0.00012345 PBTC",
+    "0.000000000000000001 PETH",
+    "0.250000000000000000 PETH",
+    "1000.000 IQ",
+    "0.001 IQ",
+    "1.000000000 EOSDT",
+    "0.123456789 EOSDT",
+    "100.0000 XPR",
+    "0.5000 XPR",
+    "50.0000 DAPP",
+    "1.0000 KARMA",
+    "2500.0000 BOID",
+    "12.3456 VIGOR",
+    "-1.0000 TLOS",
+];
+
+/// Created with a precision of 69, above the 18 that `Symbol::MAX_PRECISION` allows
+const JIAYOUY: &str = "0.000000000000000000000000000000000000000000000000000000000000000000001 JIAYOUY";
+
+#[test]
+fn test_mainnet_roundtrip() {
+    for s in ASSETS {
+        let asset: Asset = s.parse().unwrap_or_else(|e| panic!("{}: {}", s, e));
+        assert_eq!(asset.to_string(), *s);
+        assert!(asset.is_amount_within_range(), "{}", s);
+    }
+}
+
+#[test]
+fn test_mainnet_high_precision() {
+    assert_eq!(JIAYOUY.parse::<Asset>(), Err(ParseError::BadPrecision("69".to_string())));
+}